@@ -0,0 +1,34 @@
+use std::fmt;
+
+// errors returned by the try_ constructors and by Nsga3
+#[derive(Debug, Clone, PartialEq)]
+pub enum Error {
+    // a constructor or the builder was given inconsistent parameters, the message says which ones
+    InvalidParameter(String),
+    // the coord is out of the bounds of the problem or its dimension mismatches
+    CoordNotAllowed(Vec<f64>),
+    // Problem::fitness_batch did not return one fitness per coord
+    InvalidFitness { expected: usize, received: usize },
+    // the fitness of coord does not have get_dim_objective values
+    InvalidDimObjective { coord: Vec<f64>, expected: usize, received: usize },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidParameter(message) => write!(f, "{message}"),
+            Error::CoordNotAllowed(coord) => write!(
+                f,
+                "coord {coord:?} not allowed, it may be out of the bounds or its dimension may mismatch"
+            ),
+            Error::InvalidFitness { expected, received } => {
+                write!(f, "fitness_batch returned {received} fitness for {expected} coords")
+            }
+            Error::InvalidDimObjective { coord, expected, received } => {
+                write!(f, "the fitness of coord {coord:?} has {received} objectives instead of {expected}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
@@ -0,0 +1,17 @@
+// the numeric code of the crate favours explicit returns and index loops
+#![allow(
+    clippy::needless_return,
+    clippy::needless_range_loop,
+    clippy::ptr_arg,
+    clippy::manual_range_contains
+)]
+
+pub mod error;
+pub mod metrics;
+pub mod nsga3;
+pub mod operators;
+pub mod population;
+pub mod problem;
+pub mod reference;
+pub mod sorting;
+pub mod termination;
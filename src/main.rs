@@ -1,11 +1,9 @@
-mod nsga3;
-mod problem;
-
-use std::{cell::RefCell, collections::LinkedList, rc::Rc};
+use std::sync::Arc;
 
 // use nsga3::Nsga3;
-use problem::{dtzl1::DTZL1, dtzl2::DTZL2, dtzl3::DTZL3, dtzl6::DTZL6, dtzl7::DTZL7, Point};
-use nsga3::{Nsga3, non_dominated_sort};
+use nsga3::problem::{dtzl1::DTZL1, Point};
+use nsga3::nsga3::non_dominated_sort;
+use nsga3::sorting::SortAlgorithm;
 
 
 fn main() {
@@ -46,8 +44,9 @@ fn main() {
     // let p: Point<DTZL1> = Point::new_from(vec![1.;9], Rc::clone(&problem));
     // println!("{:?}",p);
 
-    let problem =Rc::new(RefCell::new(DTZL1::new(3,2))) ;
-    let points: LinkedList<Point<DTZL1>> = (0..10).map(|_| Point::new(Rc::clone(&problem))).collect();
+    let mut rng = rand::thread_rng();
+    let problem = Arc::new(DTZL1::new(3,2));
+    let points: Vec<Point<DTZL1>> = (0..10).map(|_| Point::new(Arc::clone(&problem), &mut rng)).collect();
 
     for p in points.iter() {
         println!("{:?}",p);
@@ -55,14 +54,14 @@ fn main() {
 
     println!("\n\n");
 
-    let fronts = non_dominated_sort(points);
+    let fronts = non_dominated_sort(&points, SortAlgorithm::default());
 
     // println!("{:?}",fronts);
     let mut a = 0;
-    for i in 0..fronts.len() {
-        a += fronts[i].len();
-        for p in fronts[i].iter(){
-            println!("{i} {:?}",p);
+    for (i, front) in fronts.iter().enumerate() {
+        a += front.len();
+        for &k in front.iter(){
+            println!("{i} {:?}",points[k]);
         }
     }
 
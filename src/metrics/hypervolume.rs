@@ -0,0 +1,157 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use crate::problem::{pareto_domination, Domination};
+
+// exact hypervolume of the region dominated by front and bounded by ref_point (minimisation),
+// the points that do not dominate ref_point are ignored
+// WFG algorithm from:
+// Lyndon While, Lucas Bradstreet, and Luigi Barone. A Fast Way of Calculating Exact Hypervolumes.
+// IEEE Transactions on Evolutionary Computation, 16(1):86–95, 2012.
+// its cost grows exponentially with the number of objectives, see hypervolume_monte_carlo for many objectives
+pub fn hypervolume(front: &Vec<Vec<f64>>, ref_point: &Vec<f64>) -> f64 {
+    let points: Vec<Vec<f64>> = front
+        .iter()
+        .filter(|p| p.iter().zip(ref_point).all(|(v, r)| v < r))
+        .cloned()
+        .collect();
+
+    return wfg(non_dominated(points), ref_point);
+}
+
+// hypervolume estimated from nb_samples points drawn uniformly in the box between the ideal point of the front
+// and ref_point, the error decreases in 1/sqrt(nb_samples) whatever the number of objectives
+pub fn hypervolume_monte_carlo(front: &Vec<Vec<f64>>, ref_point: &Vec<f64>, nb_samples: usize, seed: u64) -> f64 {
+    let points: Vec<&Vec<f64>> = front
+        .iter()
+        .filter(|p| p.iter().zip(ref_point).all(|(v, r)| v < r))
+        .collect();
+
+    if points.is_empty() || nb_samples == 0 {
+        return 0.;
+    }
+
+    let ideal: Vec<f64> = (0..ref_point.len())
+        .map(|j| points.iter().map(|p| p[j]).fold(f64::INFINITY, f64::min))
+        .collect();
+    let box_volume: f64 = ideal.iter().zip(ref_point).map(|(i, r)| r - i).product();
+
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut sample = vec![0.; ref_point.len()];
+    let mut nb_dominated = 0;
+
+    for _ in 0..nb_samples {
+        for j in 0..sample.len() {
+            sample[j] = rng.gen_range(ideal[j]..=ref_point[j]);
+        }
+        if points.iter().any(|p| p.iter().zip(&sample).all(|(v, s)| v <= s)) {
+            nb_dominated += 1;
+        }
+    }
+
+    return box_volume * nb_dominated as f64 / nb_samples as f64;
+}
+
+fn wfg(mut points: Vec<Vec<f64>>, ref_point: &Vec<f64>) -> f64 {
+    if points.is_empty() {
+        return 0.;
+    }
+
+    if ref_point.len() == 2 {
+        return hypervolume_2d(points, ref_point);
+    }
+
+    // sorting on the last objective makes the limit sets smaller
+    points.sort_by(|a, b| b[b.len() - 1].total_cmp(&a[a.len() - 1]));
+
+    let mut volume = 0.;
+    for k in 0..points.len() {
+        volume += exclusive_hypervolume(&points, k, ref_point);
+    }
+
+    return volume;
+}
+
+// hypervolume dominated by points[k] only, and not by the points after it
+fn exclusive_hypervolume(points: &Vec<Vec<f64>>, k: usize, ref_point: &Vec<f64>) -> f64 {
+    let inclusive: f64 = points[k].iter().zip(ref_point).map(|(v, r)| r - v).product();
+
+    let limit_set: Vec<Vec<f64>> = points[k + 1..]
+        .iter()
+        .map(|p| p.iter().zip(&points[k]).map(|(a, b)| a.max(*b)).collect())
+        .collect();
+
+    return inclusive - wfg(non_dominated(limit_set), ref_point);
+}
+
+fn hypervolume_2d(mut points: Vec<Vec<f64>>, ref_point: &Vec<f64>) -> f64 {
+    points.sort_by(|a, b| a[0].total_cmp(&b[0]));
+
+    let mut volume = 0.;
+    let mut previous_f1 = ref_point[1];
+    for p in points.iter() {
+        if p[1] < previous_f1 {
+            volume += (ref_point[0] - p[0]) * (previous_f1 - p[1]);
+            previous_f1 = p[1];
+        }
+    }
+
+    return volume;
+}
+
+// remove the dominated points and the duplicates
+fn non_dominated(points: Vec<Vec<f64>>) -> Vec<Vec<f64>> {
+    let mut kept: Vec<Vec<f64>> = Vec::with_capacity(points.len());
+
+    for p in points {
+        if kept.iter().any(|k| k == &p || matches!(pareto_domination(k, &p), Domination::Dominates)) {
+            continue;
+        }
+        kept.retain(|k| !matches!(pareto_domination(&p, k), Domination::Dominates));
+        kept.push(p);
+    }
+
+    return kept;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{hypervolume, hypervolume_monte_carlo};
+
+    #[test]
+    fn single_point() {
+        assert!((hypervolume(&vec![vec![0.5; 4]], &vec![1.; 4]) - 0.0625).abs() < 1e-12);
+        assert!((hypervolume(&vec![vec![1., 2.]], &vec![3., 3.]) - 2.).abs() < 1e-12);
+    }
+
+    #[test]
+    fn known_values_in_3d() {
+        // three boxes of volume 4 overlapping two by two (and all together) on the unit cube [3, 4]^3
+        let front = vec![vec![0., 3., 3.], vec![3., 0., 3.], vec![3., 3., 0.]];
+        assert!((hypervolume(&front, &vec![4.; 3]) - 10.).abs() < 1e-12);
+
+        // 3 * 6 - 3 * 2 + 1 by inclusion–exclusion
+        let front = vec![vec![1., 2., 3.], vec![2., 3., 1.], vec![3., 1., 2.]];
+        assert!((hypervolume(&front, &vec![4.; 3]) - 13.).abs() < 1e-12);
+    }
+
+    #[test]
+    fn ignores_dominated_duplicated_and_outside_points() {
+        let front = vec![vec![0., 3., 3.], vec![3., 0., 3.], vec![3., 3., 0.]];
+        let mut noisy = front.clone();
+        noisy.push(vec![0., 3., 3.]);
+        noisy.push(vec![3.5, 3.5, 3.5]);
+        noisy.push(vec![5., 0., 0.]);
+        noisy.push(vec![0., 4., 0.]);
+
+        assert!((hypervolume(&noisy, &vec![4.; 3]) - 10.).abs() < 1e-12);
+        assert_eq!(hypervolume(&vec![], &vec![4.; 3]), 0.);
+    }
+
+    #[test]
+    fn monte_carlo_is_close_to_exact() {
+        let front = vec![vec![1., 2., 3.], vec![2., 3., 1.], vec![3., 1., 2.]];
+
+        assert!((hypervolume_monte_carlo(&front, &vec![4.; 3], 100_000, 0) - 13.).abs() < 0.2);
+        assert!((hypervolume_monte_carlo(&vec![vec![0.5; 4]], &vec![1.; 4], 1000, 0) - 0.0625).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,122 @@
+// quality indicators of a set of fitness vectors, every objective being minimised
+// (Point::minimised_fitness, see minimised_fitness to get them from points)
+
+use crate::problem::{Point, Problem};
+
+pub mod hypervolume;
+
+pub fn minimised_fitness<T>(points: &[Point<T>]) -> Vec<Vec<f64>>
+where T: Problem + Clone
+{
+    return points.iter().map(|p| p.minimised_fitness.clone()).collect();
+}
+
+fn distance(a: &[f64], b: &[f64]) -> f64 {
+    return a.iter().zip(b).map(|(x, y)| (x - y).powi(2)).sum::<f64>().sqrt();
+}
+
+// mean over the points of a of the distance to the closest point of b
+fn mean_min_distance(a: &Vec<Vec<f64>>, b: &Vec<Vec<f64>>, dist: impl Fn(&[f64], &[f64]) -> f64) -> f64 {
+    if a.is_empty() {
+        return 0.;
+    }
+
+    let sum: f64 = a
+        .iter()
+        .map(|p| b.iter().map(|q| dist(p, q)).fold(f64::INFINITY, f64::min))
+        .sum();
+
+    return sum / a.len() as f64;
+}
+
+// generational distance: how close the front is to the reference set (usually the true Pareto front)
+pub fn gd(front: &Vec<Vec<f64>>, reference_set: &Vec<Vec<f64>>) -> f64 {
+    return mean_min_distance(front, reference_set, distance);
+}
+
+// inverted generational distance: how well the front covers the reference set
+pub fn igd(front: &Vec<Vec<f64>>, reference_set: &Vec<Vec<f64>>) -> f64 {
+    return mean_min_distance(reference_set, front, distance);
+}
+
+// IGD+ from:
+// Hisao Ishibuchi, Hiroyuki Masuda, Yuki Tanigaki, and Yusuke Nojima. Modified Distance Calculation in Generational
+// Distance and Inverted Generational Distance. EMO 2015, LNCS 9019, 110–125.
+// only the objectives where the front is worse than the reference point count, which makes it Pareto compliant
+pub fn igd_plus(front: &Vec<Vec<f64>>, reference_set: &Vec<Vec<f64>>) -> f64 {
+    return mean_min_distance(reference_set, front, |z, a| {
+        a.iter().zip(z).map(|(a, z)| (a - z).max(0.).powi(2)).sum::<f64>().sqrt()
+    });
+}
+
+// Schott's spacing: standard deviation of the Manhattan distance of every point to its closest neighbour,
+// 0 for evenly spaced points
+pub fn spacing(front: &Vec<Vec<f64>>) -> f64 {
+    let n = front.len();
+    if n < 2 {
+        return 0.;
+    }
+
+    let d: Vec<f64> = (0..n)
+        .map(|i| {
+            (0..n)
+                .filter(|&j| j != i)
+                .map(|j| front[i].iter().zip(&front[j]).map(|(a, b)| (a - b).abs()).sum::<f64>())
+                .fold(f64::INFINITY, f64::min)
+        })
+        .collect();
+    let mean = d.iter().sum::<f64>() / n as f64;
+
+    return (d.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (n - 1) as f64).sqrt();
+}
+
+// generalized spread from:
+// Aimin Zhou, Yaochu Jin, Qingfu Zhang, Bernhard Sendhoff, and Edward Tsang. Combining Model-based and Genetics-based
+// Offspring Generation for Multi-objective Optimization Using a Convergence Criterion. CEC 2006, 892–899.
+// extent and uniformity of the front with respect to the extreme points of the reference set, 0 being the best
+pub fn spread(front: &Vec<Vec<f64>>, reference_set: &Vec<Vec<f64>>) -> f64 {
+    let n = front.len();
+    if n < 2 || reference_set.is_empty() {
+        return 0.;
+    }
+    let m = reference_set[0].len();
+
+    // point of the reference set with the largest value on every objective
+    let extremes: Vec<&Vec<f64>> = (0..m)
+        .map(|j| reference_set.iter().max_by(|a, b| a[j].total_cmp(&b[j])).unwrap())
+        .collect();
+
+    let closest = |p: &Vec<f64>, skip: Option<usize>| -> f64 {
+        front
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| Some(*i) != skip)
+            .map(|(_, q)| distance(p, q))
+            .fold(f64::INFINITY, f64::min)
+    };
+
+    let d_extremes: f64 = extremes.iter().map(|e| closest(e, None)).sum();
+    let d: Vec<f64> = (0..n).map(|i| closest(&front[i], Some(i))).collect();
+    let mean = d.iter().sum::<f64>() / n as f64;
+    let deviation: f64 = d.iter().map(|v| (v - mean).abs()).sum();
+
+    let denominator = d_extremes + n as f64 * mean;
+    if denominator == 0. {
+        return 0.;
+    }
+    return (d_extremes + deviation) / denominator;
+}
+
+// Riesz s-energy sum_{i<j} 1 / ||f_i - f_j||^s, the lower the better spread
+// two identical points give an infinite energy
+pub fn riesz_energy(front: &Vec<Vec<f64>>, s: f64) -> f64 {
+    let mut energy = 0.;
+
+    for i in 0..front.len() {
+        for j in (i + 1)..front.len() {
+            energy += distance(&front[i], &front[j]).powf(-s);
+        }
+    }
+
+    return energy;
+}
@@ -1,297 +1,749 @@
-use std::{collections::LinkedList, ptr, rc::Rc};
+use std::{sync::Arc, time::Instant};
 use nalgebra::{DMatrix};
-use rand::{seq::{IteratorRandom, SliceRandom}, Rng};
+use rand::{rngs::StdRng, seq::SliceRandom, SeedableRng};
 
-use crate::problem::{self, Point, Problem};
-
-const NB_OBJ: usize = 5;
+use crate::operators::{polynomial::PolynomialMutation, sbx::SBX, selection::RandomSelection, Crossover, Mutation, Selection, SelectionInfo};
+use crate::error::Error;
+use crate::population::Population;
+use crate::problem::{Point, Problem};
+use crate::sorting::SortAlgorithm;
+use crate::termination::{Progress, Termination};
 
 pub struct Nsga3<T>
 where T: Problem + Clone
 {
-    parent_pop: LinkedList<Point<T>>,
+    problem: Arc<T>,
+    parent_pop: Population,
     ref_points: Vec<Vec<f64>>,
     pop_size: usize,
-    ideal_point: Vec<f64>
+    ideal_point: Vec<f64>,
+    // minimised fitness of the extreme points found by the last normalisation
+    extreme_points: Vec<Vec<f64>>,
+    // ranks and niches of the points of parent_pop, in the same order
+    parent_info: SelectionInfo,
+
+    selection: Box<dyn Selection>,
+    crossover: Box<dyn Crossover>,
+    mutation: Box<dyn Mutation>,
+
+    terminations: Vec<Termination>,
+    sort_algorithm: SortAlgorithm,
+    nb_threads: usize,
+    // every random choice of the run is drawn from rng, so that a seeded run can be reproduced
+    rng: StdRng,
+    // values of the indicator of every Termination::Stagnation since it last moved
+    indicator_history: Vec<Vec<f64>>,
+    progress: Progress,
 }
 
-impl<T> Nsga3<T>
+pub struct Nsga3Builder<T>
 where T: Problem + Clone
 {
-    fn new() -> Nsga3<T> {
-        Nsga3 {
-            parent_pop: LinkedList::new(),
+    problem: Arc<T>,
+    pop_size: Option<usize>,
+    ref_points: Vec<Vec<f64>>,
+    selection: Box<dyn Selection>,
+    crossover: Box<dyn Crossover>,
+    mutation: Box<dyn Mutation>,
+    terminations: Vec<Termination>,
+    sort_algorithm: SortAlgorithm,
+    nb_threads: usize,
+    seed: Option<u64>,
+}
+
+impl<T> Nsga3Builder<T>
+where T: Problem + Clone
+{
+    pub fn new(problem: Arc<T>) -> Self {
+        Self {
+            problem,
+            pop_size: None,
             ref_points: Vec::new(),
-            pop_size: 100,
-            ideal_point: Vec::new()
+            selection: Box::new(RandomSelection),
+            crossover: Box::new(SBX::default()),
+            mutation: Box::new(PolynomialMutation::default()),
+            terminations: Vec::new(),
+            sort_algorithm: SortAlgorithm::default(),
+            nb_threads: 1,
+            seed: None,
         }
     }
 
-    /* ALgorithm 1 in NSGA-III paper
-    */
-    fn iterate(&mut self) {
+    // by default the population size is the number of reference points rounded up to a multiple of 4
+    pub fn pop_size(mut self, pop_size: usize) -> Self {
+        self.pop_size = Some(pop_size);
+        return self;
+    }
 
-        let mut saturated: LinkedList<Point<T>> = LinkedList::new();
-        let mut i = 0;
-        let mut everyone: LinkedList<Point<T>> = LinkedList::new();
-        let mut fronts: Vec<LinkedList<Point<T>>> = Vec::new();
+    // ref_points can be obtained from crate::reference
+    pub fn ref_points(mut self, ref_points: Vec<Vec<f64>>) -> Self {
+        self.ref_points = ref_points;
+        return self;
+    }
 
-        self.get_offspring();
-        everyone.append(&mut self.parent_pop);
+    // RandomSelection by default
+    pub fn selection(mut self, selection: impl Selection + 'static) -> Self {
+        self.selection = Box::new(selection);
+        return self;
+    }
 
-        fronts = non_dominated_sort(everyone);
+    // SBX by default
+    pub fn crossover(mut self, crossover: impl Crossover + 'static) -> Self {
+        self.crossover = Box::new(crossover);
+        return self;
+    }
 
-        while saturated.len() < self.pop_size && i < fronts.len() {
-            saturated.append(&mut fronts[i]);
-            i+=1;
-        }
+    // PolynomialMutation by default
+    pub fn mutation(mut self, mutation: impl Mutation + 'static) -> Self {
+        self.mutation = Box::new(mutation);
+        return self;
+    }
 
-        if saturated.len() == self.pop_size || i == fronts.len() { 
-            self.parent_pop = saturated.clone();
-            return;
-        } else {
-            for j in 0..i {
-                self.parent_pop.append(&mut fronts[j]);
-            }
+    // can be called several times, the run stops at the first criterion reached
+    pub fn termination(mut self, termination: Termination) -> Self {
+        self.terminations.push(termination);
+        return self;
+    }
+
+    // SortAlgorithm::EfficientSequential by default
+    pub fn sort_algorithm(mut self, sort_algorithm: SortAlgorithm) -> Self {
+        self.sort_algorithm = sort_algorithm;
+        return self;
+    }
+
+    // number of threads evaluating the points, 1 by default
+    pub fn nb_threads(mut self, nb_threads: usize) -> Self {
+        self.nb_threads = nb_threads;
+        return self;
+    }
+
+    // with a seed the runs are reproducible (whatever the number of threads),
+    // without one the generator is seeded from the entropy of the system
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        return self;
+    }
 
-            self.normalise(&mut saturated);
-            self.associate();
-            self.niching();
+    pub fn build(self) -> Nsga3<T> {
+        return self.try_build().unwrap_or_else(|e| panic!("{e}"));
+    }
+
+    pub fn try_build(self) -> Result<Nsga3<T>, Error> {
+        if self.ref_points.is_empty() {
+            return Err(Error::InvalidParameter("Nsga3Builder::build : at least one reference point is needed".to_string()));
+        }
+        if self.ref_points.iter().any(|w| w.len() != self.problem.get_dim_objective()) {
+            return Err(Error::InvalidParameter(
+                "Nsga3Builder::build : every reference point must have one value per objective".to_string(),
+            ));
         }
+        if self.terminations.is_empty() {
+            return Err(Error::InvalidParameter(
+                "Nsga3Builder::build : at least one termination criterion is needed".to_string(),
+            ));
+        }
+
+        let pop_size = self.pop_size.unwrap_or(self.ref_points.len().div_ceil(4) * 4);
+        if pop_size < self.crossover.nb_parents().max(2) {
+            return Err(Error::InvalidParameter(
+                "Nsga3Builder::build : the population is smaller than the number of parents of the crossover".to_string(),
+            ));
+        }
+        if self.nb_threads == 0 {
+            return Err(Error::InvalidParameter("Nsga3Builder::build : at least one thread is needed".to_string()));
+        }
+
+        let nb_terminations = self.terminations.len();
+
+        return Ok(Nsga3 {
+            problem: self.problem,
+            parent_pop: Population::new(0, 0),
+            ref_points: self.ref_points,
+            pop_size,
+            ideal_point: Vec::new(),
+            extreme_points: Vec::new(),
+            parent_info: SelectionInfo::new(0),
+            selection: self.selection,
+            crossover: self.crossover,
+            mutation: self.mutation,
+            terminations: self.terminations,
+            sort_algorithm: self.sort_algorithm,
+            nb_threads: self.nb_threads,
+            rng: match self.seed {
+                Some(seed) => StdRng::seed_from_u64(seed),
+                None => StdRng::from_entropy(),
+            },
+            indicator_history: vec![vec![]; nb_terminations],
+            progress: Progress { generation: 0, nb_evaluations: 0, start: Instant::now() },
+        });
+    }
+}
+
+impl<T> Nsga3<T>
+where T: Problem + Clone
+{
+    pub fn builder(problem: Arc<T>) -> Nsga3Builder<T> {
+        return Nsga3Builder::new(problem);
     }
 
-    // crossover and mutation from:
-    // Kalyanmoy Deb, Karthik Sindhya, and Tatsuya Okabe. Self-adaptive simulated binary crossover for real-parameter optimization. 
-    // In Proceedings of the 9th Annual Conference on Genetic and Evolutionary Computation, GECCO ‘07, 1187–1194. New York, NY, USA, 2007. ACM.
-    fn get_offspring(&self ) -> LinkedList<Point<T>> {
-        // crossover + mutation from self.parent_pop
+    pub fn get_ref_points(&self) -> &Vec<Vec<f64>> {
+        return &self.ref_points;
+    }
+
+    pub fn get_pop_size(&self) -> usize {
+        return self.pop_size;
+    }
 
-        let mut rng = rand::thread_rng();
+    pub fn get_progress(&self) -> &Progress {
+        return &self.progress;
+    }
 
-        let nb_offsprings: usize = 100;
-        
-        // crossover (SBX)
-        let mut offsprings: LinkedList<Point<T>> = LinkedList::new();
-        let parent: Vec<Point<T>> = self.parent_pop.clone().into_iter().collect();
-        let problem = parent[0].get_problem(); 
-        let (lower_b,upper_b) = problem.borrow().get_bounds();
+    pub fn get_population(&self) -> &Population {
+        return &self.parent_pop;
+    }
 
-        let eta = 2.; // distribution index
+    // start from a random population and iterate until a termination criterion is reached,
+    // return the non-dominated points of the final population
+    // an error stops the run, the population of the last generation completed stays available with get_population
+    pub fn run(&mut self) -> Result<Vec<Point<T>>, Error> {
+        self.progress = Progress { generation: 0, nb_evaluations: 0, start: Instant::now() };
+        self.indicator_history = vec![vec![]; self.terminations.len()];
+        self.ideal_point = Vec::new();
+        self.extreme_points = Vec::new();
+
+        let coords: Vec<Vec<f64>> = (0..self.pop_size).map(|_| self.problem.generate_random_coord(&mut self.rng)).collect();
+        self.parent_pop = Population::evaluate(&*self.problem, coords, self.nb_threads)?;
+        self.progress.nb_evaluations += self.pop_size;
+        // only the violations are known before the first environmental selection
+        self.parent_info = SelectionInfo { violations: self.parent_pop.violations.clone(), ..SelectionInfo::new(self.pop_size) };
+
+        while !self.is_terminated() {
+            self.iterate()?;
+            self.progress.generation += 1;
+        }
 
+        return Ok(self
+            .parent_pop
+            .non_dominated_sort(self.sort_algorithm)
+            .into_iter()
+            .next()
+            .unwrap_or_default()
+            .into_iter()
+            .map(|i| Point::from_population(&self.parent_pop, i, Arc::clone(&self.problem)))
+            .collect());
+    }
 
-        fn calc_betaq(beta: f64, eta: f64, u: f64) -> f64 {
-            let alpha = 2. - beta.powf(-(eta + 1.)) ;
-            let betaq;
+    fn is_terminated(&mut self) -> bool {
+        let front: Option<Vec<Vec<f64>>> = if self.terminations.iter().any(|t| t.needs_front()) {
+            let fronts = self.parent_pop.non_dominated_sort(self.sort_algorithm);
+            // the indicators of crate::metrics expect every objective to be minimised
+            Some(fronts.first().map(|f| self.parent_pop.minimised_fitness_of(f)).unwrap_or_default())
+        } else {
+            None
+        };
 
-            if u <= (1./alpha) {
-                betaq = (u * alpha).powf(1. / (eta + 1.));
-            } else {
-                betaq = (1. / (2. - u * alpha)).powf(1. / (eta + 1.));
-            }
-            return  betaq;
+        let mut terminated = false;
+        for (termination, history) in self.terminations.iter().zip(self.indicator_history.iter_mut()) {
+            // every criterion is evaluated so that the stagnation histories stay up to date
+            terminated |= termination.is_reached(&self.progress, front.as_ref(), history);
         }
 
+        return terminated;
+    }
+
+    /* ALgorithm 1 in NSGA-III paper
+    */
+    fn iterate(&mut self) -> Result<(), Error> {
+
+        let mut saturated_index: Vec<usize> = Vec::new();
+        let mut ranks: Vec<usize> = Vec::new();
+        let mut i = 0;
+        let mut last_front_len = 0;
+        let mut everyone: Population = self.get_offspring()?;
+
+        everyone.append(&self.parent_pop);
+
+        let fronts: Vec<Vec<usize>> = everyone.non_dominated_sort(self.sort_algorithm);
+
+        while saturated_index.len() < self.pop_size && i < fronts.len() {
+            last_front_len = fronts[i].len();
+            ranks.extend(std::iter::repeat_n(i, last_front_len));
+            saturated_index.extend_from_slice(&fronts[i]);
+            i+=1;
+        }
+        let mut saturated = everyone.select(&saturated_index);
+
+        // the niches are also needed by the selection of the next generation
+        self.normalise(&mut saturated, fronts.first().map_or(0, |f| f.len()));
+        let (niches, distances) = self.associate(&saturated);
+
+        // the last front may be larger than the remaining slots even when it is the last one of everyone
+        // (e.g. every point is infeasible with the same violation)
+        let chosen: Vec<usize> = if saturated.len() <= self.pop_size {
+            (0..saturated.len()).collect()
+        } else if !saturated.is_feasible(saturated.len() - 1) {
+            // constrained NSGA-III: an infeasible last front only holds points with the same violation
+            // (smaller violations are in earlier fronts), the remaining slots are filled at random
+            let nb_selected = saturated.len() - last_front_len;
+            let mut chosen: Vec<usize> = (0..nb_selected).collect();
+            chosen.extend(
+                rand::seq::index::sample(&mut self.rng, last_front_len, self.pop_size - nb_selected)
+                    .into_iter()
+                    .map(|k| nb_selected + k),
+            );
+            chosen.sort_unstable();
+            chosen
+        } else {
+            // the points of the fronts before the last one are all kept (P_{t+1}),
+            // the remaining slots are filled with points of the last front (F_l)
+            let nb_selected = saturated.len() - last_front_len;
 
-        for _ in 0..nb_offsprings/2 {
-            let parents: Vec<&Point<T>> = parent.choose_multiple(&mut rng,2).collect();
+            self.niching(nb_selected, &niches, &distances)
+        };
 
-            let coord_size = parents[0].coord.len();
-            let cross: Vec<bool> = (0..coord_size).map(|_| rng.gen_bool(0.3)).collect();
+        self.parent_info = SelectionInfo {
+            ranks: chosen.iter().map(|&k| ranks[k]).collect(),
+            niches: chosen.iter().map(|&k| niches[k]).collect(),
+            distances: chosen.iter().map(|&k| distances[k]).collect(),
+            violations: chosen.iter().map(|&k| saturated.violations[k]).collect(),
+        };
 
+        self.parent_pop = saturated.select(&chosen);
 
-            let mut cc1: Vec<f64> = parents[0].coord.clone();
-            let mut cc2: Vec<f64> = parents[1].coord.clone();
+        return Ok(());
+    }
 
-            for i in 0..coord_size {
-                let u: f64 = rng.gen_range(0.0..=1.);
+    fn get_offspring(&mut self) -> Result<Population, Error> {
+        // selection + crossover + mutation from self.parent_pop
 
-                let y1 = parents[0].coord[i].min(parents[1].coord[i]);
-                let y2 = parents[0].coord[i].max(parents[1].coord[i]);
+        let rng = &mut self.rng;
 
-                let delta: f64 = y2 - y1;
+        let nb_offsprings: usize = self.pop_size;
 
-                if cross[i] {
-                    let beta: f64 = 1. + (2. * (y1 - lower_b) / delta);
-                    let betaq = calc_betaq(beta, eta, u);
-                    cc1[i] = 0.5 * ( (1. + betaq) * parents[0].coord[i] +  (1. - betaq) * parents[1].coord[i] );
+        let mut offsprings: Vec<Vec<f64>> = Vec::with_capacity(nb_offsprings);
+        let (lower_b,upper_b) = self.problem.get_bounds();
 
+        while offsprings.len() < nb_offsprings {
+            let parents: Vec<Vec<f64>> = self
+                .selection
+                .select(&self.parent_info, self.crossover.nb_parents(), rng)
+                .into_iter()
+                .map(|i| self.parent_pop.get_coord(i).to_vec())
+                .collect();
+            let parents: Vec<&Vec<f64>> = parents.iter().collect();
 
-                    let beta: f64 = 1. + (2. * (upper_b - y2) / delta);
-                    let betaq = calc_betaq(beta, eta, u);
-                    cc2[i] = 0.5 * ( (1. - betaq) * parents[0].coord[i] +  (1. + betaq) * parents[1].coord[i] );
+            for mut child in self.crossover.crossover(&parents, &lower_b, &upper_b, rng) {
+                if offsprings.len() == nb_offsprings {
+                    break;
                 }
+
+                self.mutation.mutate(&mut child, &lower_b, &upper_b, rng);
+                offsprings.push(child);
             }
-            
-            let c1: Point<T> = Point::new_from(cc1, Rc::clone(&problem));
-            let c2: Point<T> = Point::new_from(cc2, Rc::clone(&problem));
-            
-            offsprings.push_back(c1);
-            offsprings.push_back(c2);
         }
 
+        self.progress.nb_evaluations += offsprings.len();
 
-        return offsprings;
+        return Population::evaluate(&*self.problem, offsprings, self.nb_threads);
     }
 
-    fn normalise(&mut self, saturated: &mut LinkedList<Point<T>>) {
-        let mut extreme_points: Vec<Point<T>> = Vec::new();
-        let mut min_abs: Vec<f64> = Vec::new();
-        let mut nb_obj: usize = 0;
+    /* section IV-C in NSGA-III paper
+    the minimised fitness of every point is translated by the ideal point and divided by the intercepts of the
+    hyperplane going through the extreme points, only the feasible points take part in the normalisation
+    when the hyperplane is degenerate (singular system, negative or tiny intercepts) the worst values of the
+    first front are used instead, as in:
+    Julian Blank and Kalyanmoy Deb. pymoo: Multi-Objective Optimization in Python. IEEE Access, 8:89497–89509, 2020.
+    the first nb_first_front points of saturated are its first front
+    */
+    fn normalise(&mut self, saturated: &mut Population, nb_first_front: usize) {
+        let nb_obj: usize = saturated.get_dim_objective();
+        let feasible: Vec<usize> = (0..saturated.len()).filter(|&k| saturated.is_feasible(k)).collect();
 
-        for ele in saturated.clone().iter() {
-            let ele_fitness: Vec<f64> = ele.fitness.clone();
-            let mut w: Vec<f64> = vec![];
+        if feasible.is_empty() {
+            return;
+        }
 
-            nb_obj = ele_fitness.len();
-            
-            // initialise w
-            for _ in 0..nb_obj {
-                w.push(0.000001);
+        if self.ideal_point.is_empty() {
+            self.ideal_point = vec![f64::INFINITY; nb_obj];
+        }
+        for &k in feasible.iter() {
+            for j in 0..nb_obj {
+                self.ideal_point[j] = self.ideal_point[j].min(saturated.minimised_fitness[(j, k)]);
             }
+        }
+
+        // the extreme points of the previous generation stay candidates so that a better one is never lost
+        let mut candidates: Vec<Vec<f64>> = std::mem::take(&mut self.extreme_points);
+        candidates.extend(feasible.iter().map(|&k| saturated.get_minimised_fitness(k).to_vec()));
 
+        for j in 0..nb_obj {
+            // achievement scalarising function with the weight vector of the j-th axis (w_i = 1e-6 for i != j)
+            let asf = |f: &Vec<f64>| -> f64 {
+                (0..nb_obj)
+                    .map(|i| (f[i] - self.ideal_point[i]) / if i == j { 1. } else { 1e-6 })
+                    .fold(f64::NEG_INFINITY, f64::max)
+            };
+            let extreme = candidates.iter().min_by(|a, b| asf(a).total_cmp(&asf(b))).unwrap();
+            self.extreme_points.push(extreme.clone());
+        }
+
+        let intercepts: Vec<f64> = match self.intercepts() {
+            Some(intercepts) => intercepts,
+            None => {
+                // worst value of the first front, of every feasible point when it is too close to the ideal point
+                let worst = |points: &mut dyn Iterator<Item = &usize>, j: usize| -> f64 {
+                    points
+                        .map(|&k| saturated.minimised_fitness[(j, k)] - self.ideal_point[j])
+                        .fold(f64::NEG_INFINITY, f64::max)
+                };
+
+                (0..nb_obj)
+                    .map(|j| {
+                        let mut nadir = worst(&mut feasible.iter().filter(|&&k| k < nb_first_front), j);
+                        if nadir <= 1e-6 {
+                            nadir = worst(&mut feasible.iter(), j);
+                        }
+                        // the objective has the same value for every point, it does not need to be scaled
+                        if nadir <= 1e-6 {
+                            nadir = 1.;
+                        }
+                        nadir
+                    })
+                    .collect()
+            }
+        };
+
+        // Normalise the (minimised) fitness of every point, the original fitness is kept for the next generations
+        for k in 0..saturated.len() {
             for j in 0..nb_obj {
-                // compute ideal point
-                if ele_fitness[j] < self.ideal_point[j] {
-                    self.ideal_point[j] = ele_fitness[j];
-                }
-                
-                // compute abs
-                w[j] = 1.;
-                let mut abs = ele_fitness[0] / w[0];
-                for i in 1..nb_obj {
-                    if ele_fitness[i] / w[i] > abs {
-                        abs = ele_fitness[i] / w[i]
-                    }
-                }
-                w[j] = 0.000001;
-
-                // check for min abs
-                if min_abs.len() <= j {
-                    min_abs.push(abs);
-                    extreme_points.push(ele.clone());
-                } else if min_abs[j] > abs {
-                    min_abs[j] = abs;
-                    extreme_points[j] = ele.clone();
-                }
+                saturated.norm_fitness[(j, k)] =
+                    (saturated.minimised_fitness[(j, k)] - self.ideal_point[j]) / intercepts[j];
             }
         }
+    }
 
-        // calculate plan equation
-        let n = nb_obj;
+    // intercepts with the objective axes of the hyperplane going through the extreme points translated by the ideal point,
+    // None when the hyperplane is degenerate
+    fn intercepts(&self) -> Option<Vec<f64>> {
+        let n = self.ideal_point.len();
         let mut a = DMatrix::<f64>::from_element(n, n, 0.0);
-        let mut b = DMatrix::<f64>::from_element(n, 1, 0.0);
+        let b = DMatrix::<f64>::from_element(n, 1, 1.0);
 
-        for (i, point) in extreme_points.iter().enumerate() {
+        for (i, point) in self.extreme_points.iter().enumerate() {
             for j in 0..n {
-                a[(i, j)] = point.fitness[j];
+                a[(i, j)] = point[j] - self.ideal_point[j];
             }
-            b[i] = 1.0;
         }
 
-        let coefficients = a.lu().solve(&b).unwrap();
-        let mut a_list: Vec<f64> = vec![];
+        // the plane is sum_j coefficients_j f_j = 1 so its intercept with the j-th axis is 1 / coefficients_j
+        let coefficients = a.lu().solve(&b)?;
+        let intercepts: Vec<f64> = coefficients.iter().map(|c| 1. / c).collect();
 
-        for j in 0..nb_obj {
-            a_list.push(-1./coefficients[(1, j)]);
+        if intercepts.iter().any(|a| !a.is_finite() || *a <= 1e-6) {
+            return None;
         }
-        
+        return Some(intercepts);
+    }
 
-        // Normalise the fitness of every point
-        for ele in saturated.iter_mut() {
-            for j in 0..nb_obj {
-                // ele.set_norm_fitness((ele.fitness[j] - self.ideal_point[j]) / (a_list[j] - self.ideal_point[j]), j);
+    /* Algorithm 3 in NSGA-III paper
+    return for every point of saturated the index of the closest reference line
+    and its perpendicular distance to this line, infeasible points are not associated
+    */
+    fn associate(&self, saturated: &Population) -> (Vec<Option<usize>>, Vec<f64>) {
+        let mut niches: Vec<Option<usize>> = Vec::with_capacity(saturated.len());
+        let mut distances: Vec<f64> = Vec::with_capacity(saturated.len());
+
+        for ele in 0..saturated.len() {
+            if !saturated.is_feasible(ele) {
+                niches.push(None);
+                distances.push(f64::INFINITY);
+                continue;
+            }
+
+            let mut best = 0;
+            let mut best_distance = f64::INFINITY;
 
-                // on a besoin de la fitness normale apres ou on peut consider que la fitness normaliser c'est la nouvelle fitness ??
+            for (j, w) in self.ref_points.iter().enumerate() {
+                let distance = perpendicular_distance(saturated.get_norm_fitness(ele), w);
+                if distance < best_distance {
+                    best = j;
+                    best_distance = distance;
+                }
             }
+
+            niches.push(Some(best));
+            distances.push(best_distance);
         }
-    }
 
-    fn associate(&self) {
-        todo!()
+        return (niches, distances);
     }
 
-    fn niching(&self) {
-        todo!()
-    }
-}
+    /* Algorithm 4 in NSGA-III paper
+    the first nb_selected points of saturated are part of the next population,
+    the others belong to the last front and are picked one by one in the least crowded niches
+    return the sorted index in saturated of the points of the next population
+    */
+    fn niching(&mut self, nb_selected: usize, niches: &Vec<Option<usize>>, distances: &Vec<f64>) -> Vec<usize> {
+        let rng = &mut self.rng;
 
-// from paper: 
-// Deb K, Pratap A, Agarwal S, Meyarivan T. A fast and elitist multiobjective genetic algorithm: NSGA-IIDeb K, Pratap A, Agarwal S, Meyarivan T. A fast and elitist multiobjective genetic algorithm: NSGA-II[J].
-// Ieee Transactions on Evolutionary Computation. 2002,6(2):182-97
-pub fn non_dominated_sort<T>(pop: LinkedList<Point<T>>) -> Vec<LinkedList<Point<T>>>
-where T: Problem + Clone
-{   
-    let mut s: Vec<Vec<Point<T>>>= vec![vec![];pop.len()]; // list of point dominated by the point of the index in the pop
-    let mut s_index: Vec<Vec<usize>>= vec![vec![];pop.len()]; // list of index of pointdominated by the point of the index in the pop
-    let mut f: Vec<LinkedList<Point<T>>> = vec![];  // list of fronts
-    let mut f_index: Vec<LinkedList<usize>> = vec![]; // list of the index of point in the fronts
-    
-    let mut d_count = vec![0;pop.len()]; // counter of the number of time the point with index i in pop is dominated
-
-    // pass through all point and compare them between each other
-    for (i,p1) in pop.clone().into_iter().enumerate() {
-        for (j,p2) in pop.clone().into_iter().enumerate() {
-            if i == j {
-                // do nothing
-                continue;
-            }
-            // check domination
-            match p1.domination(&p2) {
-                crate::problem::Domination::Dominates => {
-                    // keep all the point dominated by p1
-                    s[i].push(p2);
-                    s_index[i].push(j);
-                },
-                crate::problem::Domination::Equivalent => (),
-                crate::problem::Domination::Dominated => {
-                    // count the number of time p1 is dominated
-                    d_count[i] += 1
-                },
-            }
+        // the last front is feasible so are all the points before it, they all have a niche
+        let mut niche_count: Vec<usize> = vec![0; self.ref_points.len()];
+        for &j in niches.iter().take(nb_selected).flatten() {
+            niche_count[j] += 1;
         }
-        
-        // if the point i in pop (p1) is dominated by no one
-        if d_count[i] == 0 {
-            if f.len() == 0 {
-                f.push(LinkedList::new());
-                f_index.push(LinkedList::new());
+
+        // index in saturated of the points of the last front that are still available
+        let mut last_front: Vec<usize> = (nb_selected..niches.len()).collect();
+        let mut excluded: Vec<bool> = vec![false; self.ref_points.len()];
+        let mut chosen: Vec<usize> = (0..nb_selected).collect();
+
+        while chosen.len() < self.pop_size && !last_front.is_empty() {
+            // reference points with the smallest niche count
+            let min_count = (0..niche_count.len())
+                .filter(|&j| !excluded[j])
+                .map(|j| niche_count[j])
+                .min()
+                .expect("Nsga3::niching : every reference point was excluded");
+            let j_min: Vec<usize> = (0..niche_count.len())
+                .filter(|&j| !excluded[j] && niche_count[j] == min_count)
+                .collect();
+            let j = *j_min.choose(rng).unwrap();
+
+            let candidates: Vec<usize> = (0..last_front.len())
+                .filter(|&k| niches[last_front[k]] == Some(j))
+                .collect();
+
+            if candidates.is_empty() {
+                excluded[j] = true;
+                continue;
             }
-            // add the point to the first front
-            f[0].push_back(p1.clone());
-            f_index[0].push_back(i);
+
+            let k = if niche_count[j] == 0 {
+                // the closest point to the reference line
+                *candidates
+                    .iter()
+                    .min_by(|&&a, &&b| distances[last_front[a]].total_cmp(&distances[last_front[b]]))
+                    .unwrap()
+            } else {
+                *candidates.choose(rng).unwrap()
+            };
+
+            chosen.push(last_front.swap_remove(k));
+            niche_count[j] += 1;
         }
+
+        chosen.sort_unstable();
+        return chosen;
     }
-    
-    let mut fi = 0;
-    loop {
-        let mut q: LinkedList<Point<T>> = LinkedList::new();
-        let mut q_index: LinkedList<usize> = LinkedList::new();
-
-        // go through each point of the previous front
-        for i in f_index[fi].clone().into_iter() {
-            // go through all the point dominated by a point in the previous point
-            for (p2, j) in s[i].clone().into_iter().zip(s_index[i].clone().into_iter()) {
-                // decrement the count once it reach 0 the point belong to the next front
-                // it mean that no more point dominated it in all the point that are not yet in a front 
-                d_count[j] -= 1; 
-                if d_count[j] == 0 {
-                    // add the point in the next front
-                    q.push_back(p2);
-                    q_index.push_back(j);
-                }
-            }
-        }
+}
 
-        fi += 1;
-        // if no point was added in q (next front) then we stop (all the points were taken in account)
-        if q.is_empty() {
-            break;
+// distance between point and the line going through the origin with direction w
+fn perpendicular_distance(point: &[f64], w: &Vec<f64>) -> f64 {
+    let norm_w: f64 = w.iter().map(|v| v * v).sum();
+    let projection: f64 = point.iter().zip(w).map(|(p, v)| p * v).sum::<f64>() / norm_w;
+
+    return point
+        .iter()
+        .zip(w)
+        .map(|(p, v)| (p - projection * v).powi(2))
+        .sum::<f64>()
+        .sqrt();
+}
+
+// index of the points of every non-dominated front with the constraint-domination of Point::domination,
+// see crate::sorting for the algorithms
+pub fn non_dominated_sort<T>(points: &[Point<T>], algorithm: SortAlgorithm) -> Vec<Vec<usize>>
+where T: Problem + Clone
+{
+    return Population::from_points(points).non_dominated_sort(algorithm);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use nalgebra::DMatrix;
+
+    use super::{perpendicular_distance, Nsga3};
+    use crate::metrics::igd;
+    use crate::population::Population;
+    use crate::problem::{dtzl2::DTZL2, ParetoFront};
+    use crate::reference::das_dennis;
+    use crate::termination::Termination;
+
+    fn nsga3(dim_objective: usize) -> Nsga3<DTZL2> {
+        return Nsga3::builder(Arc::new(DTZL2::new(dim_objective + 4, dim_objective)))
+            .ref_points(das_dennis(dim_objective, 4))
+            .termination(Termination::MaxGenerations(1))
+            .seed(0)
+            .build();
+    }
+
+    // feasible population of minimised objectives, one fitness vector per point
+    fn population(fitness: &[Vec<f64>]) -> Population {
+        let values = DMatrix::from_fn(fitness[0].len(), fitness.len(), |j, k| fitness[k][j]);
+        return Population {
+            coords: DMatrix::zeros(1, fitness.len()),
+            fitness: values.clone(),
+            minimised_fitness: values,
+            norm_fitness: DMatrix::zeros(fitness[0].len(), fitness.len()),
+            violations: vec![0.; fitness.len()],
+        };
+    }
+
+    fn assert_close(a: &[f64], b: &[f64]) {
+        assert!(a.iter().zip(b).all(|(x, y)| (x - y).abs() < 1e-9), "{:?} != {:?}", a, b);
+    }
+
+    #[test]
+    fn norm_fitness_is_scaled_by_the_intercepts() {
+        let mut algo = nsga3(3);
+        let mut pop = population(&[vec![2., 1., 1.], vec![1., 3., 1.], vec![1., 1., 4.], vec![1.5, 1.5, 1.5]]);
+        algo.normalise(&mut pop, 4);
+
+        assert_close(&algo.ideal_point, &[1., 1., 1.]);
+        assert_close(&algo.intercepts().unwrap(), &[1., 2., 3.]);
+        assert_close(pop.get_norm_fitness(0), &[1., 0., 0.]);
+        assert_close(pop.get_norm_fitness(1), &[0., 1., 0.]);
+        assert_close(pop.get_norm_fitness(2), &[0., 0., 1.]);
+        assert_close(pop.get_norm_fitness(3), &[0.5, 0.25, 1. / 6.]);
+    }
+
+    #[test]
+    fn degenerate_hyperplanes_have_no_intercepts() {
+        let mut algo = nsga3(3);
+        algo.ideal_point = vec![0.; 3];
+
+        algo.extreme_points = vec![vec![1., 0., 0.], vec![0., 2., 0.], vec![0., 0., 3.]];
+        assert_close(&algo.intercepts().unwrap(), &[1., 2., 3.]);
+
+        // duplicate extreme points, the system is singular
+        algo.extreme_points = vec![vec![1., 0., 0.], vec![1., 0., 0.], vec![0., 0., 1.]];
+        assert_eq!(algo.intercepts(), None);
+
+        // the plane crosses the third axis below the ideal point
+        algo.extreme_points = vec![vec![1., 0., 0.], vec![0., 1., 0.], vec![2., 2., 0.5]];
+        assert_eq!(algo.intercepts(), None);
+
+        // the plane crosses the first axis too close to the ideal point
+        algo.extreme_points = vec![vec![1e-8, 0., 0.], vec![0., 1., 0.], vec![0., 0., 1.]];
+        assert_eq!(algo.intercepts(), None);
+    }
+
+    #[test]
+    fn duplicate_extreme_points_fall_back_to_the_nadir() {
+        // (1, 1) is the extreme point of both axes and the only point of the first front,
+        // the nadir is then the worst value of every point
+        let mut algo = nsga3(2);
+        let mut pop = population(&[vec![1., 1.], vec![2., 3.], vec![3., 2.]]);
+        algo.normalise(&mut pop, 1);
+
+        assert_eq!(algo.extreme_points, vec![vec![1., 1.], vec![1., 1.]]);
+        assert_close(pop.get_norm_fitness(0), &[0., 0.]);
+        assert_close(pop.get_norm_fitness(1), &[0.5, 1.]);
+        assert_close(pop.get_norm_fitness(2), &[1., 0.5]);
+    }
+
+    #[test]
+    fn constant_objectives_have_an_intercept_of_1() {
+        let mut algo = nsga3(2);
+        let mut pop = population(&[vec![1., 5.], vec![2., 5.], vec![3., 5.]]);
+        algo.normalise(&mut pop, 1);
+
+        assert_close(pop.get_norm_fitness(0), &[0., 0.]);
+        assert_close(pop.get_norm_fitness(1), &[0.5, 0.]);
+        assert_close(pop.get_norm_fitness(2), &[1., 0.]);
+    }
+
+    #[test]
+    fn ref_points_must_match_the_objectives() {
+        let problem = Arc::new(DTZL2::new(7, 3));
+        let build = |ref_points: Vec<Vec<f64>>| {
+            Nsga3::builder(Arc::clone(&problem))
+                .ref_points(ref_points)
+                .termination(Termination::MaxGenerations(1))
+                .try_build()
+        };
+
+        assert!(build(das_dennis(3, 4)).is_ok());
+        assert!(build(das_dennis(2, 4)).is_err());
+        assert!(build(vec![vec![1., 0., 0.], vec![0., 1.]]).is_err());
+    }
+
+    #[test]
+    fn perpendicular_distance_to_the_reference_lines() {
+        assert_eq!(perpendicular_distance(&[1., 1.], &vec![1., 0.]), 1.);
+        assert_eq!(perpendicular_distance(&[1., 1.], &vec![2., 0.]), 1.);
+        assert_eq!(perpendicular_distance(&[3., 4.], &vec![0., 1.]), 3.);
+        assert!(perpendicular_distance(&[0.5, 0.5], &vec![1., 1.]) < 1e-12);
+    }
+
+    #[test]
+    fn points_are_associated_with_the_closest_reference_line() {
+        let algo = Nsga3::builder(Arc::new(DTZL2::new(6, 2)))
+            .ref_points(das_dennis(2, 2))
+            .termination(Termination::MaxGenerations(1))
+            .build();
+        let niche = |w: Vec<f64>| algo.ref_points.iter().position(|r| *r == w);
+
+        let mut pop = population(&[vec![0.9, 0.1], vec![0.4, 0.6], vec![0.1, 0.8], vec![0.5, 0.5]]);
+        pop.norm_fitness = pop.minimised_fitness.clone();
+        pop.violations[3] = 1.;
+        let (niches, distances) = algo.associate(&pop);
+
+        assert_eq!(niches, vec![niche(vec![1., 0.]), niche(vec![0.5, 0.5]), niche(vec![0., 1.]), None]);
+        assert_close(&distances[..3], &[0.1, 0.02_f64.sqrt(), 0.1]);
+        assert_eq!(distances[3], f64::INFINITY);
+    }
+
+    #[test]
+    fn niching_fills_the_population() {
+        for pop_size in 2..=7 {
+            let mut algo = Nsga3::builder(Arc::new(DTZL2::new(6, 2)))
+                .ref_points(das_dennis(2, 2))
+                .pop_size(pop_size)
+                .termination(Termination::MaxGenerations(1))
+                .seed(0)
+                .build();
+            let niches = vec![Some(0), Some(0), Some(1), Some(1), Some(2), Some(2), Some(2)];
+            let chosen = algo.niching(2, &niches, &vec![0.; 7]);
+
+            assert_eq!(chosen.len(), pop_size);
+            assert!(chosen.windows(2).all(|c| c[0] < c[1]));
+            assert_eq!(chosen[..2], [0, 1]);
         }
+    }
 
-        // add the next front (q) in the list of fronts
-        f.push(q.clone());
-        f_index.push(q_index.clone());
+    #[test]
+    fn niching_prefers_the_closest_point_of_an_empty_niche() {
+        // niche 0 has two points, niche 1 one and niche 2 none: the closest point of niche 2 is taken
+        let niches = vec![Some(0), Some(0), Some(1), Some(1), Some(2), Some(2), Some(0)];
+        let distances = vec![0., 0., 0., 0.1, 0.5, 0.2, 0.];
+
+        for seed in 0..20 {
+            let mut algo = Nsga3::builder(Arc::new(DTZL2::new(6, 2)))
+                .ref_points(das_dennis(2, 2))
+                .pop_size(4)
+                .termination(Termination::MaxGenerations(1))
+                .seed(seed)
+                .build();
+
+            assert_eq!(algo.niching(3, &niches, &distances), vec![0, 1, 2, 5]);
+        }
     }
 
-    return f;
-}
\ No newline at end of file
+    #[test]
+    fn run_converges_on_dtzl2() {
+        let problem = Arc::new(DTZL2::new(12, 3));
+        let mut algo = Nsga3::builder(Arc::clone(&problem))
+            .ref_points(das_dennis(3, 12))
+            .termination(Termination::MaxGenerations(200))
+            .seed(0)
+            .build();
+        let front: Vec<Vec<f64>> = algo.run().unwrap().into_iter().map(|p| p.fitness).collect();
+
+        assert_eq!(front.len(), 92);
+        // about 0.053 for every seed
+        assert!(igd(&front, &problem.pareto_front(1000)) < 0.06);
+    }
+}
@@ -0,0 +1,86 @@
+use rand::{Rng, RngCore};
+
+use super::Crossover;
+use crate::error::Error;
+
+// blend crossover from:
+// Larry J. Eshelman and J. David Schaffer. Real-Coded Genetic Algorithms and Interval-Schemata.
+// Foundations of Genetic Algorithms, 2:187–202, 1993.
+// every variable is drawn uniformly in the interval of the parents extended by alpha times its width on each side
+#[derive(Debug, Clone)]
+pub struct BLX {
+    alpha: f64,
+}
+
+impl BLX {
+    pub fn new(alpha: f64) -> Self {
+        return Self::try_new(alpha).unwrap_or_else(|e| panic!("{e}"));
+    }
+
+    // a negative alpha shrinks the interval, below -0.5 its bounds would cross
+    pub fn try_new(alpha: f64) -> Result<Self, Error> {
+        if alpha.is_nan() || alpha < -0.5 {
+            return Err(Error::InvalidParameter("For BLX: you must have alpha >= -0.5".to_string()));
+        }
+
+        return Ok(Self { alpha });
+    }
+}
+
+impl Default for BLX {
+    fn default() -> Self {
+        Self::new(0.5)
+    }
+}
+
+impl Crossover for BLX {
+    fn nb_parents(&self) -> usize {
+        return 2;
+    }
+
+    fn crossover(&self, parents: &Vec<&Vec<f64>>, lower_b: &Vec<f64>, upper_b: &Vec<f64>, rng: &mut dyn RngCore) -> Vec<Vec<f64>> {
+        let coord_size = parents[0].len();
+        let mut cc1: Vec<f64> = vec![0.; coord_size];
+        let mut cc2: Vec<f64> = vec![0.; coord_size];
+
+        for i in 0..coord_size {
+            let y1 = parents[0][i].min(parents[1][i]);
+            let y2 = parents[0][i].max(parents[1][i]);
+            let extent = self.alpha * (y2 - y1);
+
+            let low = (y1 - extent).max(lower_b[i]);
+            let high = (y2 + extent).min(upper_b[i]);
+
+            cc1[i] = rng.gen_range(low..=high);
+            cc2[i] = rng.gen_range(low..=high);
+        }
+
+        return vec![cc1, cc2];
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    use super::BLX;
+    use crate::operators::Crossover;
+
+    #[test]
+    fn alpha_must_keep_the_interval() {
+        assert!(BLX::try_new(-0.6).is_err());
+        assert!(BLX::try_new(f64::NAN).is_err());
+
+        // the children of the smallest alpha are the middle of the parents
+        let mut rng = StdRng::seed_from_u64(0);
+        let lower_b = vec![0.; 5];
+        let upper_b = vec![1.; 5];
+        for _ in 0..100 {
+            let p1: Vec<f64> = (0..5).map(|_| rng.gen()).collect();
+            let p2: Vec<f64> = (0..5).map(|_| rng.gen()).collect();
+            for child in BLX::new(-0.5).crossover(&vec![&p1, &p2], &lower_b, &upper_b, &mut rng) {
+                assert!(child.iter().zip(p1.iter().zip(&p2)).all(|(c, (a, b))| (c - (a + b) / 2.).abs() < 1e-12));
+            }
+        }
+    }
+}
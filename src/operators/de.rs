@@ -0,0 +1,59 @@
+use rand::{Rng, RngCore};
+
+use super::Crossover;
+use crate::error::Error;
+
+// DE/rand/1/bin from:
+// Rainer Storn and Kenneth Price. Differential Evolution – A Simple and Efficient Heuristic for global Optimization
+// over Continuous Spaces. Journal of Global Optimization, 11(4):341–359, 1997.
+// parents are [target, r1, r2, r3], the mutant r1 + f (r2 - r3) is crossed with the target
+#[derive(Debug, Clone)]
+pub struct DE {
+    // scale factor of the difference vector
+    f: f64,
+    // probability for every variable to be taken from the mutant
+    cr: f64,
+}
+
+impl DE {
+    pub fn new(f: f64, cr: f64) -> Self {
+        return Self::try_new(f, cr).unwrap_or_else(|e| panic!("{e}"));
+    }
+
+    pub fn try_new(f: f64, cr: f64) -> Result<Self, Error> {
+        if !(0. ..=1.).contains(&cr) {
+            return Err(Error::InvalidParameter("For DE: you must have 0 <= cr <= 1".to_string()));
+        }
+
+        return Ok(Self { f, cr });
+    }
+}
+
+impl Default for DE {
+    fn default() -> Self {
+        Self::new(0.5, 0.9)
+    }
+}
+
+impl Crossover for DE {
+    fn nb_parents(&self) -> usize {
+        return 4;
+    }
+
+    fn crossover(&self, parents: &Vec<&Vec<f64>>, lower_b: &Vec<f64>, upper_b: &Vec<f64>, rng: &mut dyn RngCore) -> Vec<Vec<f64>> {
+        let coord_size = parents[0].len();
+        let mut child: Vec<f64> = parents[0].clone();
+
+        // at least one variable comes from the mutant
+        let j_rand = rng.gen_range(0..coord_size);
+
+        for i in 0..coord_size {
+            if i == j_rand || rng.gen_bool(self.cr) {
+                let mutant = parents[1][i] + self.f * (parents[2][i] - parents[3][i]);
+                child[i] = mutant.clamp(lower_b[i], upper_b[i]);
+            }
+        }
+
+        return vec![child];
+    }
+}
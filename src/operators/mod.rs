@@ -0,0 +1,58 @@
+use rand::RngCore;
+
+pub mod blx;
+pub mod de;
+pub mod polynomial;
+pub mod sbx;
+pub mod selection;
+pub mod uniform;
+
+// create children from the coordinates of nb_parents() parents
+pub trait Crossover {
+    fn nb_parents(&self) -> usize;
+
+    fn crossover(&self, parents: &Vec<&Vec<f64>>, lower_b: &Vec<f64>, upper_b: &Vec<f64>, rng: &mut dyn RngCore) -> Vec<Vec<f64>>;
+}
+
+// modify in place the coordinates of a child
+pub trait Mutation {
+    fn mutate(&self, coord: &mut Vec<f64>, lower_b: &Vec<f64>, upper_b: &Vec<f64>, rng: &mut dyn RngCore);
+}
+
+// choose the index in the parent population of the nb_parents used by one crossover
+pub trait Selection {
+    fn select(&self, info: &SelectionInfo, nb_parents: usize, rng: &mut dyn RngCore) -> Vec<usize>;
+}
+
+// what the environmental selection of the previous generation knows about every point of the parent population
+#[derive(Debug, Clone)]
+pub struct SelectionInfo {
+    // index of the front of the point, 0 being the non-dominated one
+    pub ranks: Vec<usize>,
+    // reference point the point is associated with, None for the initial population
+    pub niches: Vec<Option<usize>>,
+    // perpendicular distance of the point to the reference line of its niche
+    pub distances: Vec<f64>,
+    // total violation of the constraints, see Problem::violation
+    pub violations: Vec<f64>,
+}
+
+impl SelectionInfo {
+    // nothing is known yet: every point is non-dominated, feasible and has no niche
+    pub fn new(pop_size: usize) -> Self {
+        Self {
+            ranks: vec![0; pop_size],
+            niches: vec![None; pop_size],
+            distances: vec![f64::INFINITY; pop_size],
+            violations: vec![0.; pop_size],
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        return self.ranks.len();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.ranks.is_empty();
+    }
+}
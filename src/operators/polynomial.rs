@@ -0,0 +1,83 @@
+use rand::{Rng, RngCore};
+
+use super::Mutation;
+use crate::error::Error;
+
+// polynomial mutation from:
+// Kalyanmoy Deb and Mayank Goyal. A combined genetic adaptive search (GeneAS) for engineering design.
+// Computer Science and Informatics, 26(4):30–45, 1996.
+#[derive(Debug, Clone)]
+pub struct PolynomialMutation {
+    // distribution index
+    eta: f64,
+    // probability for every variable to be mutated, 1/number of variables when None
+    prob: Option<f64>,
+}
+
+impl PolynomialMutation {
+    pub fn new(eta: f64, prob: Option<f64>) -> Self {
+        return Self::try_new(eta, prob).unwrap_or_else(|e| panic!("{e}"));
+    }
+
+    pub fn try_new(eta: f64, prob: Option<f64>) -> Result<Self, Error> {
+        if eta.is_nan() || eta < 0. {
+            return Err(Error::InvalidParameter("For PolynomialMutation: you must have eta >= 0".to_string()));
+        }
+        if prob.is_some_and(|p| !(0. ..=1.).contains(&p)) {
+            return Err(Error::InvalidParameter("For PolynomialMutation: you must have 0 <= prob <= 1".to_string()));
+        }
+
+        return Ok(Self { eta, prob });
+    }
+}
+
+impl Default for PolynomialMutation {
+    fn default() -> Self {
+        Self::new(20., None)
+    }
+}
+
+impl Mutation for PolynomialMutation {
+    fn mutate(&self, coord: &mut Vec<f64>, lower_b: &Vec<f64>, upper_b: &Vec<f64>, rng: &mut dyn RngCore) {
+        let eta = self.eta;
+        let prob = self.prob.unwrap_or(1. / coord.len() as f64);
+
+        for i in 0..coord.len() {
+            let range = upper_b[i] - lower_b[i];
+            if !rng.gen_bool(prob) || range <= 0. {
+                continue;
+            }
+
+            let delta1 = (coord[i] - lower_b[i]) / range;
+            let delta2 = (upper_b[i] - coord[i]) / range;
+            let u: f64 = rng.gen_range(0.0..1.);
+
+            // the perturbation shrinks close to the bounds
+            let deltaq = if u < 0.5 {
+                let val = 2. * u + (1. - 2. * u) * (1. - delta1).powf(eta + 1.);
+                val.powf(1. / (eta + 1.)) - 1.
+            } else {
+                let val = 2. * (1. - u) + 2. * (u - 0.5) * (1. - delta2).powf(eta + 1.);
+                1. - val.powf(1. / (eta + 1.))
+            };
+
+            coord[i] = (coord[i] + deltaq * range).clamp(lower_b[i], upper_b[i]);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PolynomialMutation;
+
+    #[test]
+    fn parameters_are_checked() {
+        assert!(PolynomialMutation::try_new(20., None).is_ok());
+        assert!(PolynomialMutation::try_new(0., Some(1.)).is_ok());
+        assert!(PolynomialMutation::try_new(-1., None).is_err());
+        assert!(PolynomialMutation::try_new(f64::NAN, None).is_err());
+        assert!(PolynomialMutation::try_new(20., Some(1.5)).is_err());
+        assert!(PolynomialMutation::try_new(20., Some(-0.1)).is_err());
+        assert!(PolynomialMutation::try_new(20., Some(f64::NAN)).is_err());
+    }
+}
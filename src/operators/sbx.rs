@@ -0,0 +1,125 @@
+use rand::{Rng, RngCore};
+
+use super::Crossover;
+use crate::error::Error;
+
+// simulated binary crossover from:
+// Kalyanmoy Deb, Karthik Sindhya, and Tatsuya Okabe. Self-adaptive simulated binary crossover for real-parameter optimization. 
+// In Proceedings of the 9th Annual Conference on Genetic and Evolutionary Computation, GECCO ‘07, 1187–1194. New York, NY, USA, 2007. ACM.
+#[derive(Debug, Clone)]
+pub struct SBX {
+    // distribution index
+    eta: f64,
+    // probability for every variable to be crossed
+    prob: f64,
+}
+
+impl SBX {
+    pub fn new(eta: f64, prob: f64) -> Self {
+        return Self::try_new(eta, prob).unwrap_or_else(|e| panic!("{e}"));
+    }
+
+    pub fn try_new(eta: f64, prob: f64) -> Result<Self, Error> {
+        if !(0. ..=1.).contains(&prob) {
+            return Err(Error::InvalidParameter("For SBX: you must have 0 <= prob <= 1".to_string()));
+        }
+
+        return Ok(Self { eta, prob });
+    }
+}
+
+impl Default for SBX {
+    fn default() -> Self {
+        Self::new(2., 0.3)
+    }
+}
+
+fn calc_betaq(beta: f64, eta: f64, u: f64) -> f64 {
+    let alpha = 2. - beta.powf(-(eta + 1.)) ;
+    if u <= (1./alpha) {
+        return (u * alpha).powf(1. / (eta + 1.));
+    } else {
+        return (1. / (2. - u * alpha)).powf(1. / (eta + 1.));
+    }
+}
+
+impl Crossover for SBX {
+    fn nb_parents(&self) -> usize {
+        return 2;
+    }
+
+    fn crossover(&self, parents: &Vec<&Vec<f64>>, lower_b: &Vec<f64>, upper_b: &Vec<f64>, rng: &mut dyn RngCore) -> Vec<Vec<f64>> {
+        let coord_size = parents[0].len();
+
+        let mut cc1: Vec<f64> = parents[0].clone();
+        let mut cc2: Vec<f64> = parents[1].clone();
+
+        for i in 0..coord_size {
+            let u: f64 = rng.gen::<f64>();
+
+            let y1 = parents[0][i].min(parents[1][i]);
+            let y2 = parents[0][i].max(parents[1][i]);
+
+            let delta: f64 = y2 - y1;
+
+            // identical values can't be crossed (beta would be infinite)
+            if rng.gen_bool(self.prob) && delta > 1e-14 {
+                // the spread towards the lower bound is bounded by y1 and the one towards the upper bound by y2
+                let beta: f64 = 1. + (2. * (y1 - lower_b[i]) / delta);
+                let betaq = calc_betaq(beta, self.eta, u);
+                let c1 = 0.5 * ((y1 + y2) - betaq * delta);
+
+                let beta: f64 = 1. + (2. * (upper_b[i] - y2) / delta);
+                let betaq = calc_betaq(beta, self.eta, u);
+                let c2 = 0.5 * ((y1 + y2) + betaq * delta);
+
+                // c1 <= c2, the children get them in a random order as in the NSGA-II code of Deb
+                if rng.gen_bool(0.5) {
+                    cc1[i] = c2;
+                    cc2[i] = c1;
+                } else {
+                    cc1[i] = c1;
+                    cc2[i] = c2;
+                }
+            }
+        }
+
+        return vec![cc1, cc2];
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    use super::SBX;
+    use crate::operators::Crossover;
+
+    #[test]
+    fn children_stay_in_the_bounds() {
+        let mut rng = StdRng::seed_from_u64(0);
+
+        for eta in [0.5, 2., 20.] {
+            let sbx = SBX::new(eta, 1.);
+            for _ in 0..1000 {
+                let lower_b: Vec<f64> = (0..5).map(|_| rng.gen_range(-10. ..0.)).collect();
+                let upper_b: Vec<f64> = lower_b.iter().map(|l| l + rng.gen_range(0.1..10.)).collect();
+                // some parents are on the bounds
+                let parent = |rng: &mut StdRng| -> Vec<f64> {
+                    (0..5)
+                        .map(|i| match rng.gen_range(0..4) {
+                            0 => lower_b[i],
+                            1 => upper_b[i],
+                            _ => rng.gen_range(lower_b[i]..=upper_b[i]),
+                        })
+                        .collect()
+                };
+                let (p1, p2) = (parent(&mut rng), parent(&mut rng));
+
+                for child in sbx.crossover(&vec![&p1, &p2], &lower_b, &upper_b, &mut rng) {
+                    assert!((0..5).all(|i| child[i] >= lower_b[i] && child[i] <= upper_b[i]), "{:?}", child);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,124 @@
+use std::cmp::Ordering;
+
+use rand::{seq::index, Rng, RngCore};
+
+use super::{Selection, SelectionInfo};
+use crate::problem::violation_order;
+
+// parents drawn uniformly without replacement
+#[derive(Debug, Clone, Default)]
+pub struct RandomSelection;
+
+impl Selection for RandomSelection {
+    fn select(&self, info: &SelectionInfo, nb_parents: usize, rng: &mut dyn RngCore) -> Vec<usize> {
+        return index::sample(rng, info.len(), nb_parents.min(info.len())).into_vec();
+    }
+}
+
+// every parent is the winner of a tournament between two random points, the one with the best rank wins
+// and ties are broken at random
+#[derive(Debug, Clone, Default)]
+pub struct BinaryTournament;
+
+impl Selection for BinaryTournament {
+    fn select(&self, info: &SelectionInfo, nb_parents: usize, rng: &mut dyn RngCore) -> Vec<usize> {
+        return (0..nb_parents)
+            .map(|_| tournament(info, rng, |a, b| info.ranks[a].cmp(&info.ranks[b])))
+            .collect();
+    }
+}
+
+// niche-based tournament from:
+// Haitham Seada and Kalyanmoy Deb. A Unified Evolutionary Optimization Procedure for Single, Multiple, and Many Objectives.
+// IEEE Transactions on Evolutionary Computation, 20(3):358–369, 2016.
+// as in its constrained version, a feasible point wins against an infeasible one and between two infeasible points
+// the smallest violation wins, two feasible points of the same niche are compared on their rank then on their
+// distance to the reference line, feasible points of different niches are not comparable and one of them is taken at random
+#[derive(Debug, Clone, Default)]
+pub struct NicheTournament;
+
+impl Selection for NicheTournament {
+    fn select(&self, info: &SelectionInfo, nb_parents: usize, rng: &mut dyn RngCore) -> Vec<usize> {
+        return (0..nb_parents)
+            .map(|_| {
+                tournament(info, rng, |a, b| {
+                    let feasible = |k: usize| info.violations[k] <= 0.;
+                    if !feasible(a) || !feasible(b) {
+                        return match (feasible(a), feasible(b)) {
+                            (true, false) => Ordering::Less,
+                            (false, true) => Ordering::Greater,
+                            _ => violation_order(info.violations[a], info.violations[b]),
+                        };
+                    }
+                    if info.niches[a].is_none() || info.niches[a] != info.niches[b] {
+                        return Ordering::Equal;
+                    }
+                    return info.ranks[a]
+                        .cmp(&info.ranks[b])
+                        .then(info.distances[a].total_cmp(&info.distances[b]));
+                })
+            })
+            .collect();
+    }
+}
+
+// the smallest of two random points for compare, a random one of them when they are equal
+fn tournament(info: &SelectionInfo, rng: &mut dyn RngCore, compare: impl Fn(usize, usize) -> Ordering) -> usize {
+    let a = rng.gen_range(0..info.len());
+    let b = rng.gen_range(0..info.len());
+
+    match compare(a, b) {
+        Ordering::Less => a,
+        Ordering::Greater => b,
+        Ordering::Equal => if rng.gen_bool(0.5) { a } else { b },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::{NicheTournament, Selection, SelectionInfo};
+
+    // number of times the point of index 1 wins among 1000 tournaments between the two points of info,
+    // it always loses when the tournament is not between itself twice (1 time out of 4)
+    fn nb_wins_of_1(info: &SelectionInfo) -> usize {
+        let mut rng = StdRng::seed_from_u64(0);
+        return NicheTournament.select(info, 1000, &mut rng).into_iter().filter(|&i| i == 1).count();
+    }
+
+    #[test]
+    fn niche_tournament_prefers_feasible_points() {
+        // the infeasible point is in the same niche with a better rank and distance
+        let info = SelectionInfo {
+            ranks: vec![1, 0],
+            niches: vec![Some(0), Some(0)],
+            distances: vec![1., 0.],
+            violations: vec![0., 0.5],
+        };
+        assert!(nb_wins_of_1(&info) < 300);
+
+        // the niches are unknown (first generation)
+        let info = SelectionInfo { violations: vec![0., 0.5], ..SelectionInfo::new(2) };
+        assert!(nb_wins_of_1(&info) < 300);
+
+        // between two infeasible points the smallest violation wins
+        let info = SelectionInfo { violations: vec![0.1, 0.5], ..SelectionInfo::new(2) };
+        assert!(nb_wins_of_1(&info) < 300);
+    }
+
+    #[test]
+    fn niche_tournament_compares_feasible_points_of_the_same_niche() {
+        let info = SelectionInfo {
+            ranks: vec![0, 0],
+            niches: vec![Some(0), Some(0)],
+            distances: vec![0.1, 0.5],
+            violations: vec![0., 0.],
+        };
+        assert!(nb_wins_of_1(&info) < 300);
+
+        // different niches are not comparable
+        let info = SelectionInfo { niches: vec![Some(0), Some(1)], ..info };
+        assert!(nb_wins_of_1(&info) > 400);
+    }
+}
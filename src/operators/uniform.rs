@@ -0,0 +1,49 @@
+use rand::{Rng, RngCore};
+
+use super::Crossover;
+use crate::error::Error;
+
+// every variable is swapped between the two parents with probability prob
+#[derive(Debug, Clone)]
+pub struct UniformCrossover {
+    prob: f64,
+}
+
+impl UniformCrossover {
+    pub fn new(prob: f64) -> Self {
+        return Self::try_new(prob).unwrap_or_else(|e| panic!("{e}"));
+    }
+
+    pub fn try_new(prob: f64) -> Result<Self, Error> {
+        if !(0. ..=1.).contains(&prob) {
+            return Err(Error::InvalidParameter("For UniformCrossover: you must have 0 <= prob <= 1".to_string()));
+        }
+
+        return Ok(Self { prob });
+    }
+}
+
+impl Default for UniformCrossover {
+    fn default() -> Self {
+        Self::new(0.5)
+    }
+}
+
+impl Crossover for UniformCrossover {
+    fn nb_parents(&self) -> usize {
+        return 2;
+    }
+
+    fn crossover(&self, parents: &Vec<&Vec<f64>>, _lower_b: &Vec<f64>, _upper_b: &Vec<f64>, rng: &mut dyn RngCore) -> Vec<Vec<f64>> {
+        let mut cc1: Vec<f64> = parents[0].clone();
+        let mut cc2: Vec<f64> = parents[1].clone();
+
+        for i in 0..cc1.len() {
+            if rng.gen_bool(self.prob) {
+                std::mem::swap(&mut cc1[i], &mut cc2[i]);
+            }
+        }
+
+        return vec![cc1, cc2];
+    }
+}
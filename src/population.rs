@@ -0,0 +1,218 @@
+use nalgebra::DMatrix;
+
+use crate::error::Error;
+use crate::problem::{Point, Problem};
+use crate::sorting::{sort_fronts, SortAlgorithm};
+
+// points of a population stored contiguously, column i of every matrix belongs to the i-th point
+// so that the values of a point are a slice, see get_coord and the other getters
+#[derive(Debug, Clone)]
+pub struct Population {
+    // dim_point x len
+    pub coords: DMatrix<f64>,
+    // dim_objective x len
+    pub fitness: DMatrix<f64>,
+    // fitness with the maximised objectives negated, used for every comparison
+    pub minimised_fitness: DMatrix<f64>,
+    // minimised fitness normalised by Nsga3 during the environmental selection, 0 until then
+    pub norm_fitness: DMatrix<f64>,
+    // total violation of the constraints of the problem
+    pub violations: Vec<f64>,
+}
+
+impl Population {
+    pub fn new(dim_point: usize, dim_objective: usize) -> Self {
+        Self {
+            coords: DMatrix::zeros(dim_point, 0),
+            fitness: DMatrix::zeros(dim_objective, 0),
+            minimised_fitness: DMatrix::zeros(dim_objective, 0),
+            norm_fitness: DMatrix::zeros(dim_objective, 0),
+            violations: vec![],
+        }
+    }
+
+    // evaluate every coord with problem, the coords must be allowed by the problem
+    // the coords are split in nb_threads contiguous chunks evaluated in parallel, each with one call to
+    // Problem::fitness_batch, the result does not depend on nb_threads
+    pub fn evaluate<T: Problem>(problem: &T, coords: Vec<Vec<f64>>, nb_threads: usize) -> Result<Self, Error> {
+        let (lower_b, _) = problem.get_bounds();
+        let dim_objective = problem.get_dim_objective();
+        let senses = problem.get_senses();
+        let n = coords.len();
+
+        if let Some(coord) = coords.iter().find(|coord| !problem.is_coord_allow(coord)) {
+            return Err(Error::CoordNotAllowed(coord.clone()));
+        }
+        if senses.len() != dim_objective {
+            return Err(Error::InvalidParameter(
+                "Population::evaluate : get_senses must give one sense per objective".to_string(),
+            ));
+        }
+
+        let evaluated: Vec<(Vec<f64>, f64)> = if nb_threads <= 1 || n <= 1 {
+            evaluate_chunk(problem, &coords)
+        } else {
+            let chunk_size = n.div_ceil(nb_threads);
+            std::thread::scope(|scope| {
+                let handles: Vec<_> = coords
+                    .chunks(chunk_size)
+                    .map(|chunk| scope.spawn(move || evaluate_chunk(problem, chunk)))
+                    .collect();
+
+                // the chunks are joined in order, a panic of the problem is forwarded to the caller
+                let mut evaluated: Vec<(Vec<f64>, f64)> = Vec::with_capacity(n);
+                for h in handles {
+                    evaluated.extend(h.join().unwrap_or_else(|e| std::panic::resume_unwind(e))?);
+                }
+                Ok(evaluated)
+            })
+        }?;
+
+        let mut fitness: Vec<f64> = Vec::with_capacity(n * dim_objective);
+        let mut minimised_fitness: Vec<f64> = Vec::with_capacity(n * dim_objective);
+        let mut violations: Vec<f64> = Vec::with_capacity(n);
+
+        for (f, violation) in evaluated {
+            violations.push(violation);
+            minimised_fitness.extend(f.iter().zip(&senses).map(|(&v, sense)| sense.minimised(v)));
+            fitness.extend(f);
+        }
+
+        return Ok(Self {
+            coords: DMatrix::from_iterator(lower_b.len(), n, coords.into_iter().flatten()),
+            fitness: DMatrix::from_vec(dim_objective, n, fitness),
+            minimised_fitness: DMatrix::from_vec(dim_objective, n, minimised_fitness),
+            norm_fitness: DMatrix::zeros(dim_objective, n),
+            violations,
+        });
+    }
+
+    pub fn from_points<T>(points: &[Point<T>]) -> Self
+    where T: Problem + Clone
+    {
+        let column = |values: Vec<&Vec<f64>>, dim: usize| -> DMatrix<f64> {
+            DMatrix::from_iterator(dim, points.len(), values.into_iter().flatten().cloned())
+        };
+        let (dim_point, dim_objective) = match points.first() {
+            Some(p) => (p.coord.len(), p.fitness.len()),
+            None => (0, 0),
+        };
+
+        Self {
+            coords: column(points.iter().map(|p| &p.coord).collect(), dim_point),
+            fitness: column(points.iter().map(|p| &p.fitness).collect(), dim_objective),
+            minimised_fitness: column(points.iter().map(|p| &p.minimised_fitness).collect(), dim_objective),
+            norm_fitness: DMatrix::from_iterator(
+                dim_objective,
+                points.len(),
+                points.iter().flat_map(|p| {
+                    // points that were never normalised have an empty norm_fitness
+                    let mut norm = p.norm_fitness.clone();
+                    norm.resize(dim_objective, 0.);
+                    norm
+                }),
+            ),
+            violations: points.iter().map(|p| p.violation).collect(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        return self.violations.len();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.violations.is_empty();
+    }
+
+    pub fn get_dim_objective(&self) -> usize {
+        return self.fitness.nrows();
+    }
+
+    pub fn get_coord(&self, i: usize) -> &[f64] {
+        return column(&self.coords, i);
+    }
+
+    pub fn get_fitness(&self, i: usize) -> &[f64] {
+        return column(&self.fitness, i);
+    }
+
+    pub fn get_minimised_fitness(&self, i: usize) -> &[f64] {
+        return column(&self.minimised_fitness, i);
+    }
+
+    pub fn get_norm_fitness(&self, i: usize) -> &[f64] {
+        return column(&self.norm_fitness, i);
+    }
+
+    pub fn is_feasible(&self, i: usize) -> bool {
+        return self.violations[i] <= 0.;
+    }
+
+    // population made of the points of index indices, in this order
+    pub fn select(&self, indices: &[usize]) -> Self {
+        Self {
+            coords: self.coords.select_columns(indices),
+            fitness: self.fitness.select_columns(indices),
+            minimised_fitness: self.minimised_fitness.select_columns(indices),
+            norm_fitness: self.norm_fitness.select_columns(indices),
+            violations: indices.iter().map(|&i| self.violations[i]).collect(),
+        }
+    }
+
+    // the points of other are added after the points of self
+    pub fn append(&mut self, other: &Population) {
+        let concat = |a: &DMatrix<f64>, b: &DMatrix<f64>| -> DMatrix<f64> {
+            // the matrices are column major, appending the columns is appending the storages
+            DMatrix::from_iterator(a.nrows(), a.ncols() + b.ncols(), a.iter().chain(b.iter()).cloned())
+        };
+
+        self.coords = concat(&self.coords, &other.coords);
+        self.fitness = concat(&self.fitness, &other.fitness);
+        self.minimised_fitness = concat(&self.minimised_fitness, &other.minimised_fitness);
+        self.norm_fitness = concat(&self.norm_fitness, &other.norm_fitness);
+        self.violations.extend_from_slice(&other.violations);
+    }
+
+    // index of the points of every non-dominated front, see crate::sorting
+    pub fn non_dominated_sort(&self, algorithm: SortAlgorithm) -> Vec<Vec<usize>> {
+        return sort_fronts(&self.minimised_fitness, &self.violations, algorithm);
+    }
+
+    // fitness of the points of index indices, as expected by crate::metrics
+    pub fn fitness_of(&self, indices: &[usize]) -> Vec<Vec<f64>> {
+        return indices.iter().map(|&i| self.get_fitness(i).to_vec()).collect();
+    }
+
+    pub fn minimised_fitness_of(&self, indices: &[usize]) -> Vec<Vec<f64>> {
+        return indices.iter().map(|&i| self.get_minimised_fitness(i).to_vec()).collect();
+    }
+}
+
+// fitness and violation of every coord, the fitness of the whole chunk is computed with one call to fitness_batch
+fn evaluate_chunk<T: Problem>(problem: &T, coords: &[Vec<f64>]) -> Result<Vec<(Vec<f64>, f64)>, Error> {
+    let fitness = problem.fitness_batch(coords);
+    if fitness.len() != coords.len() {
+        return Err(Error::InvalidFitness { expected: coords.len(), received: fitness.len() });
+    }
+
+    // a wrong size would shift the objectives of the following points in the matrices
+    let dim_objective = problem.get_dim_objective();
+    if let Some((f, coord)) = fitness.iter().zip(coords).find(|(f, _)| f.len() != dim_objective) {
+        return Err(Error::InvalidDimObjective { coord: coord.clone(), expected: dim_objective, received: f.len() });
+    }
+
+    return Ok(fitness
+        .into_iter()
+        .zip(coords)
+        .map(|(fitness, coord)| {
+            let violation = problem.violation(coord, &fitness);
+            (fitness, violation)
+        })
+        .collect());
+}
+
+// column i of a column major matrix as a slice
+pub(crate) fn column(matrix: &DMatrix<f64>, i: usize) -> &[f64] {
+    let n = matrix.nrows();
+    return &matrix.as_slice()[i * n..(i + 1) * n];
+}
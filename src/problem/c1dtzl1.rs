@@ -0,0 +1,47 @@
+use super::{dtzl1::DTZL1, Problem};
+use crate::error::Error;
+
+// C1-DTLZ1 from:
+// Himanshu Jain and Kalyanmoy Deb. An Evolutionary Many-Objective Optimization Algorithm Using Reference-Point Based
+// Nondominated Sorting Approach, Part II. IEEE Transactions on Evolutionary Computation, 18(4):602–622, 2014.
+// DTLZ1 where only the part of the objective space close to the Pareto front is feasible
+#[derive(Debug, Clone)]
+pub struct C1DTZL1 {
+    dtzl1: DTZL1,
+    dim_objective: usize,
+}
+
+impl C1DTZL1 {
+    pub fn new(dim_point:usize,dim_objective: usize) -> Self {
+        return Self::try_new(dim_point, dim_objective).unwrap_or_else(|e| panic!("{e}"));
+    }
+
+    pub fn try_new(dim_point: usize, dim_objective: usize) -> Result<Self, Error> {
+        return Ok(Self {
+            dtzl1: DTZL1::try_new(dim_point, dim_objective)?,
+            dim_objective,
+        });
+    }
+}
+
+impl Problem for C1DTZL1 {
+    fn fitness(&self, coord: &Vec<f64>) -> Vec<f64> {
+        return self.dtzl1.fitness(coord);
+    }
+
+    fn get_bounds(&self) -> (Vec<f64>, Vec<f64>) {
+        return self.dtzl1.get_bounds();
+    }
+
+    fn get_dim_objective(&self) -> usize {
+        return self.dim_objective;
+    }
+
+    // c(x) = 1 - f_M / 0.6 - sum_{i<M} f_i / 0.5 >= 0
+    fn inequality_constraints(&self, _coord: &Vec<f64>, fitness: &Vec<f64>) -> Vec<f64> {
+        let m = self.dim_objective;
+        let sum: f64 = fitness.iter().take(m - 1).map(|f| f / 0.5).sum();
+
+        return vec![fitness[m - 1] / 0.6 + sum - 1.];
+    }
+}
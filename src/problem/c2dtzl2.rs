@@ -0,0 +1,58 @@
+use super::{dtzl2::DTZL2, Problem};
+use crate::error::Error;
+
+// C2-DTLZ2 from:
+// Himanshu Jain and Kalyanmoy Deb. An Evolutionary Many-Objective Optimization Algorithm Using Reference-Point Based
+// Nondominated Sorting Approach, Part II. IEEE Transactions on Evolutionary Computation, 18(4):602–622, 2014.
+// DTLZ2 where only small regions of the Pareto front are feasible: around every corner and around the centre
+#[derive(Debug, Clone)]
+pub struct C2DTZL2 {
+    dtzl2: DTZL2,
+    dim_objective: usize,
+    // radius of the feasible regions
+    r: f64,
+}
+
+impl C2DTZL2 {
+    pub fn new(dim_point:usize,dim_objective: usize) -> Self {
+        return Self::try_new(dim_point, dim_objective).unwrap_or_else(|e| panic!("{e}"));
+    }
+
+    pub fn try_new(dim_point: usize, dim_objective: usize) -> Result<Self, Error> {
+        return Ok(Self {
+            dtzl2: DTZL2::try_new(dim_point, dim_objective)?,
+            dim_objective,
+            r: if dim_objective == 3 { 0.4 } else { 0.5 },
+        });
+    }
+}
+
+impl Problem for C2DTZL2 {
+    fn fitness(&self, coord: &Vec<f64>) -> Vec<f64> {
+        return self.dtzl2.fitness(coord);
+    }
+
+    fn get_bounds(&self) -> (Vec<f64>, Vec<f64>) {
+        return self.dtzl2.get_bounds();
+    }
+
+    fn get_dim_objective(&self) -> usize {
+        return self.dim_objective;
+    }
+
+    // c(x) = -min[ min_i ((f_i - 1)^2 + sum_{j!=i} f_j^2 - r^2), sum_i (f_i - 1/sqrt(M))^2 - r^2 ] >= 0
+    fn inequality_constraints(&self, _coord: &Vec<f64>, fitness: &Vec<f64>) -> Vec<f64> {
+        let m = self.dim_objective;
+        let r2 = self.r.powi(2);
+        let sum_squares: f64 = fitness.iter().map(|f| f * f).sum();
+
+        let corners = (0..m)
+            .map(|i| sum_squares - fitness[i].powi(2) + (fitness[i] - 1.).powi(2) - r2)
+            .fold(f64::INFINITY, f64::min);
+
+        let centre_coord = 1. / (m as f64).sqrt();
+        let centre: f64 = fitness.iter().map(|f| (f - centre_coord).powi(2)).sum::<f64>() - r2;
+
+        return vec![corners.min(centre)];
+    }
+}
@@ -0,0 +1,49 @@
+use super::{dtzl4::DTZL4, Problem};
+use crate::error::Error;
+
+// C3-DTLZ4 from:
+// Himanshu Jain and Kalyanmoy Deb. An Evolutionary Many-Objective Optimization Algorithm Using Reference-Point Based
+// Nondominated Sorting Approach, Part II. IEEE Transactions on Evolutionary Computation, 18(4):602–622, 2014.
+// DTLZ4 whose Pareto front is pushed beyond the unit sphere by M constraints
+#[derive(Debug, Clone)]
+pub struct C3DTZL4 {
+    dtzl4: DTZL4,
+    dim_objective: usize,
+}
+
+impl C3DTZL4 {
+    pub fn new(dim_point:usize,dim_objective: usize) -> Self {
+        return Self::try_new(dim_point, dim_objective).unwrap_or_else(|e| panic!("{e}"));
+    }
+
+    pub fn try_new(dim_point: usize, dim_objective: usize) -> Result<Self, Error> {
+        return Ok(Self {
+            dtzl4: DTZL4::try_new(dim_point, dim_objective)?,
+            dim_objective,
+        });
+    }
+}
+
+impl Problem for C3DTZL4 {
+    fn fitness(&self, coord: &Vec<f64>) -> Vec<f64> {
+        return self.dtzl4.fitness(coord);
+    }
+
+    fn get_bounds(&self) -> (Vec<f64>, Vec<f64>) {
+        return self.dtzl4.get_bounds();
+    }
+
+    fn get_dim_objective(&self) -> usize {
+        return self.dim_objective;
+    }
+
+    // c_j(x) = f_j^2 / 4 + sum_{i!=j} f_i^2 - 1 >= 0 for every objective j
+    fn inequality_constraints(&self, _coord: &Vec<f64>, fitness: &Vec<f64>) -> Vec<f64> {
+        let sum_squares: f64 = fitness.iter().map(|f| f * f).sum();
+
+        return fitness
+            .iter()
+            .map(|f| 1. - (sum_squares - f * f + f * f / 4.))
+            .collect();
+    }
+}
@@ -0,0 +1,90 @@
+use std::fmt;
+
+use super::{Problem, Sense};
+use crate::error::Error;
+
+// problem defined by a closure computing the fitness, e.g. for a bi-objective problem on [0, 1]^2:
+// ClosureProblem::new(2, vec![0.; 2], vec![1.; 2], |x: &Vec<f64>| vec![x[0], 1. - x[0] * x[1]])
+#[derive(Clone)]
+pub struct ClosureProblem<F>
+where F: Fn(&Vec<f64>) -> Vec<f64> + Clone
+{
+    fitness: F,
+    lower_b: Vec<f64>,
+    upper_b: Vec<f64>,
+    dim_objective: usize,
+    senses: Vec<Sense>,
+}
+
+impl<F> ClosureProblem<F>
+where F: Fn(&Vec<f64>) -> Vec<f64> + Clone
+{
+    // every objective is minimised, see with_senses
+    pub fn new(dim_objective: usize, lower_b: Vec<f64>, upper_b: Vec<f64>, fitness: F) -> Self {
+        return Self::try_new(dim_objective, lower_b, upper_b, fitness).unwrap_or_else(|e| panic!("{e}"));
+    }
+
+    pub fn try_new(dim_objective: usize, lower_b: Vec<f64>, upper_b: Vec<f64>, fitness: F) -> Result<Self, Error> {
+        if lower_b.len() != upper_b.len() {
+            return Err(Error::InvalidParameter("For ClosureProblem: lower_b and upper_b must have the same size".to_string()));
+        }
+        if lower_b.iter().zip(&upper_b).any(|(l, u)| l > u) {
+            return Err(Error::InvalidParameter("For ClosureProblem: you must have lower_b <= upper_b".to_string()));
+        }
+
+        return Ok(Self {
+            fitness,
+            lower_b,
+            upper_b,
+            dim_objective,
+            senses: vec![Sense::Minimise; dim_objective],
+        });
+    }
+
+    pub fn with_senses(self, senses: Vec<Sense>) -> Self {
+        return self.try_with_senses(senses).unwrap_or_else(|e| panic!("{e}"));
+    }
+
+    pub fn try_with_senses(mut self, senses: Vec<Sense>) -> Result<Self, Error> {
+        if senses.len() != self.dim_objective {
+            return Err(Error::InvalidParameter("For ClosureProblem: you must give one sense per objective".to_string()));
+        }
+
+        self.senses = senses;
+        return Ok(self);
+    }
+}
+
+impl<F> fmt::Debug for ClosureProblem<F>
+where F: Fn(&Vec<f64>) -> Vec<f64> + Clone
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ClosureProblem")
+            .field("lower_b", &self.lower_b)
+            .field("upper_b", &self.upper_b)
+            .field("dim_objective", &self.dim_objective)
+            .field("senses", &self.senses)
+            .finish()
+    }
+}
+
+// the closure is shared between the evaluation threads
+impl<F> Problem for ClosureProblem<F>
+where F: Fn(&Vec<f64>) -> Vec<f64> + Clone + Send + Sync
+{
+    fn fitness(&self, coord: &Vec<f64>) -> Vec<f64> {
+        return (self.fitness)(coord);
+    }
+
+    fn get_bounds(&self) -> (Vec<f64>, Vec<f64>) {
+        return (self.lower_b.clone(), self.upper_b.clone());
+    }
+
+    fn get_dim_objective(&self) -> usize {
+        return self.dim_objective;
+    }
+
+    fn get_senses(&self) -> Vec<Sense> {
+        return self.senses.clone();
+    }
+}
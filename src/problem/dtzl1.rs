@@ -1,9 +1,9 @@
 
 use std::{f64::consts::PI, vec};
 
-use rand::Rng;
-
-use super::Problem;
+use super::{ParetoFront, Problem};
+use crate::error::Error;
+use crate::reference::simplex_points;
 
 
 #[derive(Debug, Clone)]
@@ -14,15 +14,21 @@ pub struct DTZL1 {
 
 impl DTZL1 {
     pub fn new(dim_point:usize,dim_objective: usize) -> Self {
+        return Self::try_new(dim_point, dim_objective).unwrap_or_else(|e| panic!("{e}"));
+    }
 
+    pub fn try_new(dim_point: usize, dim_objective: usize) -> Result<Self, Error> {
+        if dim_objective == 0 {
+            return Err(Error::InvalidParameter("For DTZL1: you must have dim_objective > 0".to_string()));
+        }
         if dim_point <= dim_objective {
-            panic!("For DTZL1: you must have dim_point > dim_objective ")
+            return Err(Error::InvalidParameter("For DTZL1: you must have dim_point > dim_objective".to_string()));
         }
 
-        Self {
+        return Ok(Self {
             dim_objective,
             dim_point,
-        }
+        });
     }
 
     fn g_func(&self, coord: &Vec<f64>) -> f64 {
@@ -57,18 +63,22 @@ impl Problem for DTZL1 {
         return res;
     }
     
-    fn generate_random_coord(&self) -> Vec<f64> {
-        let mut rng = rand::thread_rng();
-        return (0..self.dim_point).map(|_| rng.gen_range(0.0..=1.)).collect();
-    }
-    
-    fn is_coord_allow(&self,coord: &Vec<f64>) -> bool {
-        return coord.len() == self.dim_point && coord.iter().all(|&v| {v >= 0. && v <= 1.});
+    fn get_bounds(&self) -> (Vec<f64>, Vec<f64>) {
+        return (vec![0.; self.dim_point], vec![1.; self.dim_point]);
     }
 
-    fn get_bounds(&self) -> (f64, f64) {
-        return (0., 1.);
+    fn get_dim_objective(&self) -> usize {
+        return self.dim_objective;
     }
 
 }
-    
\ No newline at end of file
+
+// the hyperplane sum f_i = 0.5
+impl ParetoFront for DTZL1 {
+    fn pareto_front(&self, nb_points: usize) -> Vec<Vec<f64>> {
+        return simplex_points(nb_points, self.dim_objective)
+            .into_iter()
+            .map(|p| p.into_iter().map(|v| v * 0.5).collect())
+            .collect();
+    }
+}
@@ -0,0 +1,89 @@
+
+use std::{f64::consts::PI, vec};
+
+use super::{ParetoFront, Problem};
+use crate::error::Error;
+use crate::reference::simplex_points;
+
+
+// DTLZ2 with a biased density of solutions along the Pareto front
+const ALPHA: f64 = 100.;
+
+#[derive(Debug, Clone)]
+pub struct DTZL4 {
+    dim_point: usize,
+    dim_objective: usize,
+}
+
+impl DTZL4 {
+    pub fn new(dim_point:usize,dim_objective: usize) -> Self {
+        return Self::try_new(dim_point, dim_objective).unwrap_or_else(|e| panic!("{e}"));
+    }
+
+    pub fn try_new(dim_point: usize, dim_objective: usize) -> Result<Self, Error> {
+        if dim_objective == 0 {
+            return Err(Error::InvalidParameter("For DTZL4: you must have dim_objective > 0".to_string()));
+        }
+        if dim_point <= dim_objective {
+            return Err(Error::InvalidParameter("For DTZL4: you must have dim_point > dim_objective".to_string()));
+        }
+
+        return Ok(Self {
+            dim_objective,
+            dim_point,
+        });
+    }
+
+    fn g_func(&self, coord: &Vec<f64>) -> f64 {
+        let sum = coord.clone()
+                            .into_iter()
+                            .skip(self.dim_objective - 1 )
+                            .fold(0.,|acc ,v| acc + (v-0.5).powf(2.));
+        return sum
+    }
+}
+
+impl Problem for DTZL4 {
+    fn fitness(&self, coord: &Vec<f64>) -> Vec<f64> {
+        let g = self.g_func(coord);
+
+        let mut res = vec![1.+g;self.dim_objective];
+
+        // compute f starting from f_{M-1} -> f_{M-2} -> ... -> f_0
+        res = res.clone().into_iter().enumerate().map(|(i, v)| {
+            let mut end = 1.;   
+
+            for j in 0..i {
+                end *= (coord[j].powf(ALPHA)*PI/2.).cos();
+            }
+
+            if i < self.dim_objective -1 {
+                end *= (coord[i].powf(ALPHA)*PI/2.).sin();
+            }            
+            return v * end ;
+        }).rev().collect();
+
+        return res;
+    }
+    
+    fn get_bounds(&self) -> (Vec<f64>, Vec<f64>) {
+        return (vec![0.; self.dim_point], vec![1.; self.dim_point]);
+    }
+
+    fn get_dim_objective(&self) -> usize {
+        return self.dim_objective;
+    }
+}
+
+// the unit sphere
+impl ParetoFront for DTZL4 {
+    fn pareto_front(&self, nb_points: usize) -> Vec<Vec<f64>> {
+        return simplex_points(nb_points, self.dim_objective)
+            .into_iter()
+            .map(|p| {
+                let norm = p.iter().map(|v| v * v).sum::<f64>().sqrt();
+                return p.into_iter().map(|v| v / norm).collect();
+            })
+            .collect();
+    }
+}
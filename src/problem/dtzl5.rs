@@ -0,0 +1,103 @@
+
+use std::{f64::consts::PI, vec};
+
+use super::{ParetoFront, Problem};
+use crate::error::Error;
+
+
+#[derive(Debug, Clone)]
+pub struct DTZL5 {
+    dim_point: usize,
+    dim_objective: usize,
+}
+
+impl DTZL5 {
+    pub fn new(dim_point:usize,dim_objective: usize) -> Self {
+        return Self::try_new(dim_point, dim_objective).unwrap_or_else(|e| panic!("{e}"));
+    }
+
+    pub fn try_new(dim_point: usize, dim_objective: usize) -> Result<Self, Error> {
+        if dim_objective == 0 {
+            return Err(Error::InvalidParameter("For DTZL5: you must have dim_objective > 0".to_string()));
+        }
+        if dim_point <= dim_objective {
+            return Err(Error::InvalidParameter("For DTZL5: you must have dim_point > dim_objective".to_string()));
+        }
+
+        return Ok(Self {
+            dim_objective,
+            dim_point,
+        });
+    }
+
+    fn g_func(&self, coord: &Vec<f64>) -> f64 {
+        let sum = coord.clone()
+                            .into_iter()
+                            .skip(self.dim_objective - 1 )
+                            .fold(0.,|acc ,v| acc + (v-0.5).powf(2.));
+        return sum
+    }
+}
+
+// angles of DTZL5 and DTZL6: only the first one spans [0, pi/2], the others collapse to pi/4 on the Pareto front (g = 0)
+// which makes the front a curve
+pub(crate) fn theta_func(coord: &Vec<f64>, dim_objective: usize, g: f64) -> Vec<f64> {
+    return coord
+        .iter()
+        .take(dim_objective - 1)
+        .enumerate()
+        .map(|(i, &v)| {
+            if i == 0 {
+                return v * PI / 2.;
+            }
+            return PI / (4. * (1. + g)) * (1. + 2. * g * v);
+        })
+        .collect();
+}
+
+impl Problem for DTZL5 {
+    fn fitness(&self, coord: &Vec<f64>) -> Vec<f64> {
+        let g = self.g_func(coord);
+        let theta = theta_func(coord, self.dim_objective, g);
+
+        let mut res = vec![1.+g;self.dim_objective];
+
+        // compute f starting from f_{M-1} -> f_{M-2} -> ... -> f_0
+        res = res.clone().into_iter().enumerate().map(|(i, v)| {
+            let mut end = 1.;   
+
+            for j in 0..i {
+                end *= theta[j].cos();
+            }
+
+            if i < self.dim_objective -1 {
+                end *= theta[i].sin();
+            }            
+            return v * end ;
+        }).rev().collect();
+
+        return res;
+    }
+    
+    fn get_bounds(&self) -> (Vec<f64>, Vec<f64>) {
+        return (vec![0.; self.dim_point], vec![1.; self.dim_point]);
+    }
+
+    fn get_dim_objective(&self) -> usize {
+        return self.dim_objective;
+    }
+}
+
+// a curve on the unit sphere, reached for g = 0 (distance variables at 0.5) where only the first position variable matters
+impl ParetoFront for DTZL5 {
+    fn pareto_front(&self, nb_points: usize) -> Vec<Vec<f64>> {
+        return (0..nb_points)
+            .map(|i| {
+                let t = if nb_points > 1 { i as f64 / (nb_points - 1) as f64 } else { 0.5 };
+                let mut coord = vec![0.5; self.dim_point];
+                coord[0] = t;
+                return self.fitness(&coord);
+            })
+            .collect();
+    }
+}
@@ -1,8 +1,7 @@
-use std::{f64::consts::PI, vec};
+use std::vec;
 
-use rand::Rng;
-
-use super::Problem;
+use super::{dtzl5::theta_func, ParetoFront, Problem};
+use crate::error::Error;
 
 
 #[derive(Debug, Clone)]
@@ -13,15 +12,21 @@ pub struct DTZL6 {
 
 impl DTZL6 {
     pub fn new(dim_point:usize,dim_objective: usize) -> Self {
+        return Self::try_new(dim_point, dim_objective).unwrap_or_else(|e| panic!("{e}"));
+    }
 
+    pub fn try_new(dim_point: usize, dim_objective: usize) -> Result<Self, Error> {
+        if dim_objective == 0 {
+            return Err(Error::InvalidParameter("For DTZL6: you must have dim_objective > 0".to_string()));
+        }
         if dim_point <= dim_objective {
-            panic!("For DTZL6: you must have dim_point > dim_objective ")
+            return Err(Error::InvalidParameter("For DTZL6: you must have dim_point > dim_objective".to_string()));
         }
 
-        Self {
+        return Ok(Self {
             dim_objective,
             dim_point,
-        }
+        });
     }
 
     fn g_func(&self, coord: &Vec<f64>) -> f64 {
@@ -36,6 +41,7 @@ impl DTZL6 {
 impl Problem for DTZL6 {
     fn fitness(&self, coord: &Vec<f64>) -> Vec<f64> {
         let g = self.g_func(coord);
+        let theta = theta_func(coord, self.dim_objective, g);
 
         let mut res = vec![1.+g;self.dim_objective];
 
@@ -44,11 +50,11 @@ impl Problem for DTZL6 {
             let mut end = 1.;   
 
             for j in 0..i {
-                end *= (coord[j]*PI/2.).cos();
+                end *= theta[j].cos();
             }
 
             if i < self.dim_objective -1 {
-                end *= (coord[i]*PI/2.).sin();
+                end *= theta[i].sin();
             }            
             return v * end ;
         }).rev().collect();
@@ -56,17 +62,25 @@ impl Problem for DTZL6 {
         return res;
     }
     
-    fn generate_random_coord(&self) -> Vec<f64> {
-        let mut rng = rand::thread_rng();
-        return (0..self.dim_point).map(|_| rng.gen_range(0.0..=1.)).collect();
+    fn get_bounds(&self) -> (Vec<f64>, Vec<f64>) {
+        return (vec![0.; self.dim_point], vec![1.; self.dim_point]);
     }
-    
-    fn is_coord_allow(&self,coord: &Vec<f64>) -> bool {
-        return coord.len() == self.dim_point && coord.iter().all(|&v| {v >= 0. && v <= 1.});
+
+    fn get_dim_objective(&self) -> usize {
+        return self.dim_objective;
     }
+}
 
-    fn get_bounds(&self) -> (f64, f64) {
-        return (0., 1.);
+// a curve on the unit sphere, reached for g = 0 (distance variables at 0) where only the first position variable matters
+impl ParetoFront for DTZL6 {
+    fn pareto_front(&self, nb_points: usize) -> Vec<Vec<f64>> {
+        return (0..nb_points)
+            .map(|i| {
+                let t = if nb_points > 1 { i as f64 / (nb_points - 1) as f64 } else { 0.5 };
+                let mut coord = vec![0.; self.dim_point];
+                coord[0] = t;
+                return self.fitness(&coord);
+            })
+            .collect();
     }
 }
-    
\ No newline at end of file
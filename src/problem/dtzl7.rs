@@ -1,8 +1,7 @@
 use std::f64::consts::PI;
 
-use rand::Rng;
-
-use super::Problem;
+use super::{pareto_domination, Domination, ParetoFront, Problem};
+use crate::error::Error;
 
 
 #[derive(Debug, Clone)]
@@ -13,15 +12,21 @@ pub struct DTZL7 {
 
 impl DTZL7 {
     pub fn new(dim_point:usize,dim_objective: usize) -> Self {
+        return Self::try_new(dim_point, dim_objective).unwrap_or_else(|e| panic!("{e}"));
+    }
 
+    pub fn try_new(dim_point: usize, dim_objective: usize) -> Result<Self, Error> {
+        if dim_objective == 0 {
+            return Err(Error::InvalidParameter("For DTZL7: you must have dim_objective > 0".to_string()));
+        }
         if dim_point <= dim_objective {
-            panic!("For DTZL7: you must have dim_point > dim_objective ")
+            return Err(Error::InvalidParameter("For DTZL7: you must have dim_point > dim_objective".to_string()));
         }
 
-        Self {
+        return Ok(Self {
             dim_objective,
             dim_point,
-        }
+        });
     }
 
     fn g_func(&self, coord: &Vec<f64>) -> f64 {
@@ -56,17 +61,54 @@ impl Problem for DTZL7 {
         return res;
     }
     
-    fn generate_random_coord(&self) -> Vec<f64> {
-        let mut rng = rand::thread_rng();
-        return (0..self.dim_point).map(|_| rng.gen_range(0.0..=1.)).collect();
+    fn get_bounds(&self) -> (Vec<f64>, Vec<f64>) {
+        return (vec![0.; self.dim_point], vec![1.; self.dim_point]);
     }
-    
-    fn is_coord_allow(&self,coord: &Vec<f64>) -> bool {
-        return coord.len() == self.dim_point && coord.iter().all(|&v| {v >= 0. && v <= 1.});
+
+    fn get_dim_objective(&self) -> usize {
+        return self.dim_objective;
     }
+}
+
+// 2^(M-1) disconnected regions, reached for g = 1 (distance variables at 0): the non-dominated part of
+// the surface spanned by the position variables
+impl ParetoFront for DTZL7 {
+    fn pareto_front(&self, nb_points: usize) -> Vec<Vec<f64>> {
+        if nb_points == 0 {
+            return vec![];
+        }
+
+        // regular grid on the position variables, about 10 times denser than needed
+        let dim_position = self.dim_objective - 1;
+        let mut resolution: usize = 2;
+        while resolution.pow(dim_position as u32) < 10 * nb_points {
+            resolution += 1;
+        }
+
+        let surface: Vec<Vec<f64>> = (0..resolution.pow(dim_position as u32))
+            .map(|mut index| {
+                let mut coord = vec![0.; self.dim_point];
+                for j in 0..dim_position {
+                    coord[j] = (index % resolution) as f64 / (resolution - 1) as f64;
+                    index /= resolution;
+                }
+                return self.fitness(&coord);
+            })
+            .collect();
+
+        let mut front: Vec<Vec<f64>> = surface
+            .iter()
+            .filter(|f| !surface.iter().any(|other| matches!(pareto_domination(other, f), Domination::Dominates)))
+            .cloned()
+            .collect();
+        front.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        // evenly spaced subset
+        if front.len() > nb_points {
+            let step = front.len() as f64 / nb_points as f64;
+            front = (0..nb_points).map(|i| front[(i as f64 * step) as usize].clone()).collect();
+        }
 
-    fn get_bounds(&self) -> (f64, f64) {
-        return (0., 1.);
+        return front;
     }
 }
-    
\ No newline at end of file
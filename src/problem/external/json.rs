@@ -0,0 +1,253 @@
+// minimal JSON reader for the one line messages of the protocol of ExternalProblem
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Json {
+    Null,
+    Bool(bool),
+    Number(f64),
+    Str(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+impl Json {
+    pub(crate) fn parse(text: &str) -> Result<Json, String> {
+        let mut parser = Parser { bytes: text.as_bytes(), pos: 0 };
+        let value = parser.value()?;
+
+        parser.skip_whitespace();
+        if parser.pos != parser.bytes.len() {
+            return Err(format!("unexpected character at position {}", parser.pos));
+        }
+        return Ok(value);
+    }
+
+    // value of key when self is an object
+    pub(crate) fn get(&self, key: &str) -> Option<&Json> {
+        match self {
+            Json::Object(fields) => fields.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+}
+
+struct Parser<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn skip_whitespace(&mut self) {
+        while self.pos < self.bytes.len() && self.bytes[self.pos].is_ascii_whitespace() {
+            self.pos += 1;
+        }
+    }
+
+    fn peek(&mut self) -> Option<u8> {
+        self.skip_whitespace();
+        return self.bytes.get(self.pos).copied();
+    }
+
+    fn expect(&mut self, c: u8) -> Result<(), String> {
+        if self.peek() != Some(c) {
+            return Err(format!("expected '{}' at position {}", c as char, self.pos));
+        }
+        self.pos += 1;
+        return Ok(());
+    }
+
+    fn literal(&mut self, word: &str, value: Json) -> Result<Json, String> {
+        if !self.bytes[self.pos..].starts_with(word.as_bytes()) {
+            return Err(format!("unexpected character at position {}", self.pos));
+        }
+        self.pos += word.len();
+        return Ok(value);
+    }
+
+    fn value(&mut self) -> Result<Json, String> {
+        match self.peek() {
+            None => Err("unexpected end of input".to_string()),
+            Some(b'{') => self.object(),
+            Some(b'[') => self.array(),
+            Some(b'"') => Ok(Json::Str(self.string()?)),
+            Some(b'n') => self.literal("null", Json::Null),
+            Some(b't') => self.literal("true", Json::Bool(true)),
+            Some(b'f') => self.literal("false", Json::Bool(false)),
+            Some(_) => self.number(),
+        }
+    }
+
+    fn object(&mut self) -> Result<Json, String> {
+        self.expect(b'{')?;
+        let mut fields: Vec<(String, Json)> = vec![];
+
+        if self.peek() == Some(b'}') {
+            self.pos += 1;
+            return Ok(Json::Object(fields));
+        }
+
+        loop {
+            if self.peek() != Some(b'"') {
+                return Err(format!("expected a key at position {}", self.pos));
+            }
+            let key = self.string()?;
+            self.expect(b':')?;
+            fields.push((key, self.value()?));
+
+            match self.peek() {
+                Some(b',') => self.pos += 1,
+                Some(b'}') => {
+                    self.pos += 1;
+                    return Ok(Json::Object(fields));
+                }
+                _ => return Err(format!("expected ',' or '}}' at position {}", self.pos)),
+            }
+        }
+    }
+
+    fn array(&mut self) -> Result<Json, String> {
+        self.expect(b'[')?;
+        let mut values: Vec<Json> = vec![];
+
+        if self.peek() == Some(b']') {
+            self.pos += 1;
+            return Ok(Json::Array(values));
+        }
+
+        loop {
+            values.push(self.value()?);
+
+            match self.peek() {
+                Some(b',') => self.pos += 1,
+                Some(b']') => {
+                    self.pos += 1;
+                    return Ok(Json::Array(values));
+                }
+                _ => return Err(format!("expected ',' or ']' at position {}", self.pos)),
+            }
+        }
+    }
+
+    fn string(&mut self) -> Result<String, String> {
+        self.expect(b'"')?;
+        let mut bytes: Vec<u8> = vec![];
+
+        loop {
+            let c = *self.bytes.get(self.pos).ok_or("unterminated string")?;
+            self.pos += 1;
+
+            match c {
+                b'"' => break,
+                b'\\' => {
+                    let escaped = *self.bytes.get(self.pos).ok_or("unterminated string")?;
+                    self.pos += 1;
+                    match escaped {
+                        b'"' | b'\\' | b'/' => bytes.push(escaped),
+                        b'b' => bytes.push(0x08),
+                        b'f' => bytes.push(0x0c),
+                        b'n' => bytes.push(b'\n'),
+                        b'r' => bytes.push(b'\r'),
+                        b't' => bytes.push(b'\t'),
+                        b'u' => {
+                            let hex = self.bytes.get(self.pos..self.pos + 4).ok_or("unterminated string")?;
+                            let code = u32::from_str_radix(&String::from_utf8_lossy(hex), 16)
+                                .map_err(|_| format!("invalid escape at position {}", self.pos))?;
+                            self.pos += 4;
+                            // surrogate pairs are not needed by the protocol
+                            let mut buffer = [0; 4];
+                            let c = char::from_u32(code).unwrap_or(char::REPLACEMENT_CHARACTER);
+                            bytes.extend_from_slice(c.encode_utf8(&mut buffer).as_bytes());
+                        }
+                        _ => return Err(format!("invalid escape at position {}", self.pos - 1)),
+                    }
+                }
+                _ => bytes.push(c),
+            }
+        }
+
+        return String::from_utf8(bytes).map_err(|_| "invalid UTF-8 in string".to_string());
+    }
+
+    fn number(&mut self) -> Result<Json, String> {
+        let start = self.pos;
+        while self.pos < self.bytes.len() && matches!(self.bytes[self.pos], b'0'..=b'9' | b'-' | b'+' | b'.' | b'e' | b'E') {
+            self.pos += 1;
+        }
+
+        let text = std::str::from_utf8(&self.bytes[start..self.pos]).unwrap();
+        return text
+            .parse::<f64>()
+            .map(Json::Number)
+            .map_err(|_| format!("invalid number at position {}", start));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Json;
+
+    #[test]
+    fn parses_numbers() {
+        assert_eq!(Json::parse("0"), Ok(Json::Number(0.)));
+        assert_eq!(Json::parse("-12.5"), Ok(Json::Number(-12.5)));
+        assert_eq!(Json::parse("1e3"), Ok(Json::Number(1000.)));
+        assert_eq!(Json::parse("2.5E-2"), Ok(Json::Number(0.025)));
+        assert!(Json::parse("1.2.3").is_err());
+        assert!(Json::parse("-").is_err());
+    }
+
+    #[test]
+    fn parses_escapes() {
+        assert_eq!(Json::parse(r#""a\"b\\c\/d""#), Ok(Json::Str("a\"b\\c/d".to_string())));
+        assert_eq!(Json::parse(r#""\n\t\r\b\f""#), Ok(Json::Str("\n\t\r\u{8}\u{c}".to_string())));
+        assert_eq!(Json::parse(r#""\u00e9\u0041""#), Ok(Json::Str("éA".to_string())));
+        assert_eq!(Json::parse("\"é\""), Ok(Json::Str("é".to_string())));
+        assert!(Json::parse(r#""\x""#).is_err());
+        assert!(Json::parse(r#""\u00g0""#).is_err());
+    }
+
+    #[test]
+    fn parses_nested_values() {
+        let json = Json::parse(r#" {"f": [1, [2, {"a": null}]], "ok": true, "no": false, "e": {}, "l": []} "#).unwrap();
+
+        assert_eq!(
+            json.get("f"),
+            Some(&Json::Array(vec![
+                Json::Number(1.),
+                Json::Array(vec![Json::Number(2.), Json::Object(vec![("a".to_string(), Json::Null)])]),
+            ]))
+        );
+        assert_eq!(json.get("ok"), Some(&Json::Bool(true)));
+        assert_eq!(json.get("no"), Some(&Json::Bool(false)));
+        assert_eq!(json.get("e"), Some(&Json::Object(vec![])));
+        assert_eq!(json.get("l"), Some(&Json::Array(vec![])));
+        assert_eq!(json.get("missing"), None);
+        assert_eq!(Json::Number(1.).get("f"), None);
+    }
+
+    #[test]
+    fn rejects_malformed_input() {
+        for text in [
+            "",
+            "   ",
+            "{",
+            "[1, 2",
+            "[1 2]",
+            "{\"f\" [1]}",
+            "{f: [1]}",
+            "{\"f\": [1],}",
+            "\"unterminated",
+            "nul",
+            "tru",
+        ] {
+            assert!(Json::parse(text).is_err(), "{:?} should not parse", text);
+        }
+    }
+
+    #[test]
+    fn rejects_trailing_garbage() {
+        assert!(Json::parse("{\"f\": [1]} x").is_err());
+        assert!(Json::parse("[1]]").is_err());
+        assert!(Json::parse("true false").is_err());
+        assert_eq!(Json::parse("[1] \n"), Ok(Json::Array(vec![Json::Number(1.)])));
+    }
+}
@@ -0,0 +1,427 @@
+use std::{
+    io::{BufRead, BufReader, Write},
+    process::{Child, ChildStdin, Command, Stdio},
+    sync::{mpsc, Arc, Mutex},
+    thread,
+    time::Duration,
+};
+
+use self::json::Json;
+use super::{Problem, Sense};
+use crate::error::Error;
+
+mod json;
+
+// problem whose fitness is computed by an external program, with a line based JSON protocol:
+// the program reads a request {"x":[0.5,0.25]} and writes back {"f":[1.2,3.4]} (one value per objective)
+// or {"error":"message"} on one line each
+// by default the program is started for every evaluation and gets a single request,
+// see persistent for programs answering requests in a loop
+// an evaluation that fails (error message, crash, timeout, invalid answer) does not panic: the point gets
+// the worst possible fitness (f64::MAX, f64::MIN when maximised) and violates the inequality constraint of the problem,
+// see get_nb_failures
+#[derive(Debug, Clone)]
+pub struct ExternalProblem {
+    program: String,
+    args: Vec<String>,
+    lower_b: Vec<f64>,
+    upper_b: Vec<f64>,
+    dim_objective: usize,
+    senses: Vec<Sense>,
+    timeout: Option<Duration>,
+    persistent: bool,
+
+    // running programs of the persistent mode, one per evaluation in progress at most
+    workers: Arc<Mutex<Vec<Worker>>>,
+    failures: Arc<Mutex<Failures>>,
+}
+
+// statistics of the failed evaluations, only the last one is kept so that long runs do not pile them up
+#[derive(Debug, Default)]
+struct Failures {
+    count: usize,
+    last: Option<(Vec<f64>, String)>,
+}
+
+// program of the persistent mode, its standard output is read line by line by a thread
+#[derive(Debug)]
+struct Worker {
+    child: Child,
+    stdin: ChildStdin,
+    lines: mpsc::Receiver<String>,
+}
+
+impl Drop for Worker {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+impl ExternalProblem {
+    // every objective is minimised, see with_senses
+    pub fn new(program: &str, dim_objective: usize, lower_b: Vec<f64>, upper_b: Vec<f64>) -> Self {
+        return Self::try_new(program, dim_objective, lower_b, upper_b).unwrap_or_else(|e| panic!("{e}"));
+    }
+
+    pub fn try_new(program: &str, dim_objective: usize, lower_b: Vec<f64>, upper_b: Vec<f64>) -> Result<Self, Error> {
+        if lower_b.len() != upper_b.len() {
+            return Err(Error::InvalidParameter("For ExternalProblem: lower_b and upper_b must have the same size".to_string()));
+        }
+        if lower_b.iter().zip(&upper_b).any(|(l, u)| l > u) {
+            return Err(Error::InvalidParameter("For ExternalProblem: you must have lower_b <= upper_b".to_string()));
+        }
+
+        return Ok(Self {
+            program: program.to_string(),
+            args: vec![],
+            lower_b,
+            upper_b,
+            dim_objective,
+            senses: vec![Sense::Minimise; dim_objective],
+            timeout: None,
+            persistent: false,
+            workers: Arc::new(Mutex::new(vec![])),
+            failures: Arc::new(Mutex::new(Failures::default())),
+        });
+    }
+
+    pub fn with_args(mut self, args: Vec<String>) -> Self {
+        self.args = args;
+        return self;
+    }
+
+    pub fn with_senses(self, senses: Vec<Sense>) -> Self {
+        return self.try_with_senses(senses).unwrap_or_else(|e| panic!("{e}"));
+    }
+
+    pub fn try_with_senses(mut self, senses: Vec<Sense>) -> Result<Self, Error> {
+        if senses.len() != self.dim_objective {
+            return Err(Error::InvalidParameter("For ExternalProblem: you must give one sense per objective".to_string()));
+        }
+
+        self.senses = senses;
+        return Ok(self);
+    }
+
+    // maximum duration of one evaluation, the program is killed when it is reached
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        return self;
+    }
+
+    // keep the programs running between evaluations, they must answer the requests one line after the other
+    // a program is started per evaluation thread of Nsga3, and restarted after a failure
+    pub fn persistent(mut self) -> Self {
+        self.persistent = true;
+        return self;
+    }
+
+    // number of evaluations that failed since the problem was created (its clones share it)
+    pub fn get_nb_failures(&self) -> usize {
+        return self.failures.lock().unwrap().count;
+    }
+
+    // coord of the last evaluation that failed, with the reason
+    pub fn get_last_failure(&self) -> Option<(Vec<f64>, String)> {
+        return self.failures.lock().unwrap().last.clone();
+    }
+
+    fn evaluate(&self, coord: &Vec<f64>) -> Result<Vec<f64>, String> {
+        let request = format!(
+            "{{\"x\":[{}]}}\n",
+            coord.iter().map(|v| v.to_string()).collect::<Vec<String>>().join(",")
+        );
+
+        let line = if self.persistent {
+            self.evaluate_persistent(&request)?
+        } else {
+            self.evaluate_spawn(&request)?
+        };
+
+        return self.parse_response(&line);
+    }
+
+    fn command(&self) -> Command {
+        let mut command = Command::new(&self.program);
+        command.args(&self.args).stdin(Stdio::piped()).stdout(Stdio::piped()).stderr(Stdio::inherit());
+        return command;
+    }
+
+    // the lines written by the program on its standard output, read by a thread so that they can be waited with a timeout
+    fn read_lines(child: &mut Child) -> mpsc::Receiver<String> {
+        let stdout = child.stdout.take().unwrap();
+        let (sender, receiver) = mpsc::channel();
+
+        thread::spawn(move || {
+            for line in BufReader::new(stdout).lines() {
+                // stop at a read error or when the problem no longer listens
+                let sent = match line {
+                    Ok(line) => sender.send(line).is_ok(),
+                    Err(_) => false,
+                };
+                if !sent {
+                    break;
+                }
+            }
+        });
+
+        return receiver;
+    }
+
+    fn receive(&self, lines: &mpsc::Receiver<String>) -> Result<String, String> {
+        let line = match self.timeout {
+            Some(timeout) => lines.recv_timeout(timeout).map_err(|e| match e {
+                mpsc::RecvTimeoutError::Timeout => format!("no answer after {:?}", timeout),
+                mpsc::RecvTimeoutError::Disconnected => "the program closed its output".to_string(),
+            }),
+            None => lines.recv().map_err(|_| "the program closed its output".to_string()),
+        };
+        return line;
+    }
+
+    fn evaluate_spawn(&self, request: &str) -> Result<String, String> {
+        let mut child = self.command().spawn().map_err(|e| format!("cannot start {}: {}", self.program, e))?;
+        let lines = Self::read_lines(&mut child);
+
+        // the program gets an end of file after the request
+        let written = child.stdin.take().unwrap().write_all(request.as_bytes());
+
+        let line = match written {
+            Ok(()) => self.receive(&lines),
+            Err(e) => Err(format!("cannot write the request: {}", e)),
+        };
+
+        // nothing is expected from the program after its answer
+        let _ = child.kill();
+        let _ = child.wait();
+
+        return line;
+    }
+
+    fn evaluate_persistent(&self, request: &str) -> Result<String, String> {
+        let worker = self.workers.lock().unwrap().pop();
+        let mut worker = match worker {
+            Some(worker) => worker,
+            None => {
+                let mut child = self.command().spawn().map_err(|e| format!("cannot start {}: {}", self.program, e))?;
+                let lines = Self::read_lines(&mut child);
+                let stdin = child.stdin.take().unwrap();
+                Worker { child, stdin, lines }
+            }
+        };
+
+        // on a failure the worker is dropped by the ? (which kills it) as its state is unknown
+        worker
+            .stdin
+            .write_all(request.as_bytes())
+            .and_then(|_| worker.stdin.flush())
+            .map_err(|e| format!("cannot write the request: {}", e))?;
+        let line = self.receive(&worker.lines)?;
+
+        self.workers.lock().unwrap().push(worker);
+
+        return Ok(line);
+    }
+
+    fn parse_response(&self, line: &str) -> Result<Vec<f64>, String> {
+        let response = Json::parse(line).map_err(|e| format!("invalid answer {:?}: {}", line, e))?;
+
+        if let Some(error) = response.get("error") {
+            return match error {
+                Json::Str(message) => Err(message.clone()),
+                other => Err(format!("{:?}", other)),
+            };
+        }
+
+        let fitness: Vec<f64> = match response.get("f") {
+            Some(Json::Array(values)) => values
+                .iter()
+                .map(|v| match v {
+                    Json::Number(n) => Ok(*n),
+                    _ => Err(format!("invalid answer {:?}: \"f\" must only hold numbers", line)),
+                })
+                .collect::<Result<Vec<f64>, String>>()?,
+            _ => return Err(format!("invalid answer {:?}: \"f\" or \"error\" is expected", line)),
+        };
+
+        if fitness.len() != self.dim_objective {
+            return Err(format!("{} objectives received instead of {}", fitness.len(), self.dim_objective));
+        }
+        if fitness.iter().any(|f| !f.is_finite()) {
+            return Err(format!("invalid answer {:?}: the objectives must be finite", line));
+        }
+
+        return Ok(fitness);
+    }
+}
+
+impl Problem for ExternalProblem {
+    fn fitness(&self, coord: &Vec<f64>) -> Vec<f64> {
+        match self.evaluate(coord) {
+            Ok(fitness) => fitness,
+            Err(message) => {
+                let mut failures = self.failures.lock().unwrap();
+                failures.count += 1;
+                failures.last = Some((coord.clone(), message));
+                drop(failures);
+
+                // worst value of every objective
+                self.senses
+                    .iter()
+                    .map(|sense| match sense {
+                        Sense::Minimise => f64::MAX,
+                        Sense::Maximise => f64::MIN,
+                    })
+                    .collect()
+            }
+        }
+    }
+
+    fn get_bounds(&self) -> (Vec<f64>, Vec<f64>) {
+        return (self.lower_b.clone(), self.upper_b.clone());
+    }
+
+    // the failed evaluations, recognised by their worst possible fitness, are infeasible
+    // (a value beyond f64::MAX is accepted as the wrappers may scale or shift the fitness)
+    fn inequality_constraints(&self, _coord: &Vec<f64>, fitness: &Vec<f64>) -> Vec<f64> {
+        let failed = fitness.iter().zip(&self.senses).all(|(&v, sense)| sense.minimised(v) >= f64::MAX);
+        return vec![if failed { 1. } else { 0. }];
+    }
+
+    fn get_dim_objective(&self) -> usize {
+        return self.dim_objective;
+    }
+
+    fn get_senses(&self) -> Vec<Sense> {
+        return self.senses.clone();
+    }
+}
+
+// the programs are small sh scripts
+#[cfg(all(test, unix))]
+mod tests {
+    use std::time::Duration;
+
+    use super::ExternalProblem;
+    use crate::problem::{Problem, Sense};
+
+    fn script(script: &str, dim_objective: usize) -> ExternalProblem {
+        return ExternalProblem::new("sh", dim_objective, vec![0.; 2], vec![1.; 2])
+            .with_args(vec!["-c".to_string(), script.to_string()]);
+    }
+
+    // answers the number of requests it has read so far
+    const COUNTER: &str = r#"n=0; while read l; do n=$((n+1)); echo "{\"f\":[$n]}"; done"#;
+
+    #[test]
+    fn spawn_sends_the_coord() {
+        // the request {"x":[...]} becomes the answer {"f":[...]}
+        let problem = ExternalProblem::new("sed", 2, vec![0.; 2], vec![1.; 2]).with_args(vec!["s/\"x\"/\"f\"/".to_string()]);
+        let coord = vec![0.25, 0.5];
+
+        assert_eq!(problem.fitness(&coord), coord);
+        assert_eq!(problem.violation(&coord, &coord), 0.);
+        assert_eq!(problem.get_nb_failures(), 0);
+    }
+
+    #[test]
+    fn spawn_starts_a_program_per_evaluation() {
+        let problem = script(COUNTER, 1);
+
+        assert_eq!(problem.fitness(&vec![0.; 2]), vec![1.]);
+        assert_eq!(problem.fitness(&vec![0.; 2]), vec![1.]);
+    }
+
+    #[test]
+    fn persistent_keeps_the_program() {
+        let problem = script(COUNTER, 1).persistent();
+        let coords = vec![vec![0.; 2], vec![0.5; 2], vec![1.; 2]];
+
+        assert_eq!(problem.fitness_batch(&coords), vec![vec![1.], vec![2.], vec![3.]]);
+        // the clones share the running program
+        assert_eq!(problem.clone().fitness(&coords[0]), vec![4.]);
+    }
+
+    #[test]
+    fn persistent_restarts_after_a_failure() {
+        // exits after its second answer
+        let problem = script(r#"n=0; while read l; do n=$((n+1)); echo "{\"f\":[$n]}"; [ $n = 2 ] && exit; done"#, 1).persistent();
+        let coord = vec![0.; 2];
+
+        assert_eq!(problem.fitness(&coord), vec![1.]);
+        assert_eq!(problem.fitness(&coord), vec![2.]);
+        assert_eq!(problem.fitness(&coord), vec![f64::MAX]);
+        assert_eq!(problem.violation(&coord, &vec![f64::MAX]), 1.);
+        assert_eq!(problem.fitness(&coord), vec![1.]);
+        assert_eq!(problem.get_nb_failures(), 1);
+    }
+
+    #[test]
+    fn timeout_fails_the_evaluation() {
+        let problem = script("sleep 5", 2).with_timeout(Duration::from_millis(100));
+        let coord = vec![0.5; 2];
+
+        let fitness = problem.fitness(&coord);
+        assert_eq!(fitness, vec![f64::MAX; 2]);
+        assert_eq!(problem.violation(&coord, &fitness), 1.);
+
+        let (failed, message) = problem.get_last_failure().unwrap();
+        assert_eq!(failed, coord);
+        assert!(message.contains("no answer"), "{}", message);
+    }
+
+    #[test]
+    fn error_reply_fails_the_evaluation() {
+        let problem = script(r#"read l; echo '{"error":"cannot mesh"}'"#, 2).with_senses(vec![Sense::Minimise, Sense::Maximise]);
+        let coord = vec![0.5; 2];
+
+        let fitness = problem.fitness(&coord);
+        assert_eq!(fitness, vec![f64::MAX, f64::MIN]);
+        assert_eq!(problem.violation(&coord, &fitness), 1.);
+        assert_eq!(problem.get_last_failure(), Some((coord.clone(), "cannot mesh".to_string())));
+
+        // the violation only depends on the fitness
+        assert_eq!(problem.violation(&coord, &fitness), 1.);
+        assert_eq!(problem.violation(&coord, &vec![1., 2.]), 0.);
+        assert_eq!(problem.get_nb_failures(), 1);
+    }
+
+    #[test]
+    fn success_after_a_failure_is_feasible() {
+        // fails while the marker file (given as $0) exists, and removes it
+        let marker = std::env::temp_dir().join(format!("nsga3_external_marker_{}", std::process::id()));
+        std::fs::write(&marker, "").unwrap();
+        let problem = ExternalProblem::new("sh", 2, vec![0.; 2], vec![1.; 2]).with_args(vec![
+            "-c".to_string(),
+            r#"read l; if [ -e "$0" ]; then rm "$0"; echo '{"error":"first"}'; else echo '{"f":[1,2]}'; fi"#.to_string(),
+            marker.to_string_lossy().to_string(),
+        ]);
+        let coord = vec![0.5; 2];
+
+        // the constraint of the failure is never read
+        assert_eq!(problem.fitness(&coord), vec![f64::MAX; 2]);
+
+        let fitness = problem.fitness(&coord);
+        assert_eq!(fitness, vec![1., 2.]);
+        assert_eq!(problem.violation(&coord, &fitness), 0.);
+        assert_eq!(problem.get_nb_failures(), 1);
+    }
+
+    #[test]
+    fn invalid_replies_fail_the_evaluation() {
+        for answer in ["not json", r#"{"f":[1]}"#, r#"{"f":[1,"a"]}"#, r#"{"g":[1,2]}"#] {
+            let problem = script(&format!("read l; echo '{}'", answer), 2);
+            let coord = vec![0.5; 2];
+
+            let fitness = problem.fitness(&coord);
+            assert_eq!(fitness, vec![f64::MAX; 2], "{}", answer);
+            assert_eq!(problem.violation(&coord, &fitness), 1., "{}", answer);
+        }
+
+        // the program closes its output without answering
+        let problem = script("exit 1", 2);
+        problem.fitness(&vec![0.5; 2]);
+        assert_eq!(problem.get_nb_failures(), 1);
+    }
+}
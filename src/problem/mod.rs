@@ -1,10 +1,41 @@
-use std::{cell::RefCell, rc::Rc};
+use std::sync::Arc;
 
-pub(crate) mod dtzl1;
-pub(crate) mod dtzl2;
-pub(crate) mod dtzl3;
-pub(crate) mod dtzl6;
-pub(crate) mod dtzl7;
+use rand::{Rng, RngCore};
+
+use crate::error::Error;
+use crate::population::Population;
+
+pub mod c1dtzl1;
+pub mod c2dtzl2;
+pub mod c3dtzl4;
+pub mod closure;
+pub mod dtzl1;
+pub mod dtzl2;
+pub mod dtzl3;
+pub mod dtzl4;
+pub mod dtzl5;
+pub mod dtzl6;
+pub mod dtzl7;
+pub mod external;
+pub mod wfg;
+pub mod wrapper;
+
+// whether an objective has to be minimised or maximised
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Sense {
+    Minimise,
+    Maximise,
+}
+
+impl Sense {
+    // value of the objective once expressed as a minimisation
+    pub fn minimised(&self, value: f64) -> f64 {
+        match self {
+            Sense::Minimise => value,
+            Sense::Maximise => -value,
+        }
+    }
+}
 
 pub enum Domination {
     Dominates,
@@ -12,14 +43,79 @@ pub enum Domination {
     Dominated,
 }
 
-pub trait Problem {
+// a problem is shared by the points and the evaluation threads of Nsga3, fitness must not rely on interior mutability
+// that is not thread safe
+pub trait Problem: Send + Sync {
     fn fitness(&self, coord: &Vec<f64>) -> Vec<f64>;
 
-    fn generate_random_coord(&self) -> Vec<f64>;
+    // fitness of every coord, in the same order, Nsga3 gives all the points of a generation at once
+    // (or the chunk of each thread) so that they can be vectorised or sent together to an external solver
+    fn fitness_batch(&self, coords: &[Vec<f64>]) -> Vec<Vec<f64>> {
+        return coords.iter().map(|coord| self.fitness(coord)).collect();
+    }
 
-    fn is_coord_allow(&self,coord: &Vec<f64>) -> bool;
+    // uniform in the bounds by default
+    fn generate_random_coord(&self, rng: &mut dyn RngCore) -> Vec<f64> {
+        let (lower_b, upper_b) = self.get_bounds();
+        return lower_b.into_iter().zip(upper_b).map(|(l, u)| rng.gen_range(l..=u)).collect();
+    }
+
+    // in the bounds by default
+    fn is_coord_allow(&self,coord: &Vec<f64>) -> bool {
+        let (lower_b, upper_b) = self.get_bounds();
+        return coord.len() == lower_b.len()
+            && coord.iter().enumerate().all(|(i, &v)| v >= lower_b[i] && v <= upper_b[i]);
+    }
+
+    // lower and upper bound of every variable
+    fn get_bounds(&self) -> (Vec<f64>, Vec<f64>);
+
+    // inequality constraints, satisfied when g(coord) <= 0
+    // the fitness is given as many constraints are expressed on the objectives
+    fn inequality_constraints(&self, _coord: &Vec<f64>, _fitness: &Vec<f64>) -> Vec<f64> {
+        return vec![];
+    }
+
+    // equality constraints, satisfied when |h(coord)| <= get_equality_tolerance()
+    fn equality_constraints(&self, _coord: &Vec<f64>, _fitness: &Vec<f64>) -> Vec<f64> {
+        return vec![];
+    }
+
+    fn get_equality_tolerance(&self) -> f64 {
+        return 1e-4;
+    }
 
-    fn get_bounds(&self) -> (f64, f64);
+    // total violation of the constraints, 0 for a feasible point
+    // a constraint that evaluates to NaN is violated (f64::max would ignore it)
+    fn violation(&self, coord: &Vec<f64>, fitness: &Vec<f64>) -> f64 {
+        let tolerance = self.get_equality_tolerance();
+
+        let inequality: f64 = self
+            .inequality_constraints(coord, fitness)
+            .into_iter()
+            .map(|g| if g.is_nan() { f64::INFINITY } else { g.max(0.) })
+            .sum();
+        let equality: f64 = self
+            .equality_constraints(coord, fitness)
+            .into_iter()
+            .map(|h| if h.is_nan() { f64::INFINITY } else { (h.abs() - tolerance).max(0.) })
+            .sum();
+
+        return inequality + equality;
+    }
+
+    fn get_dim_objective(&self) -> usize;
+
+    // sense of every objective, all of them are minimised by default
+    fn get_senses(&self) -> Vec<Sense> {
+        return vec![Sense::Minimise; self.get_dim_objective()];
+    }
+}
+
+// problems whose true Pareto front is known, the sampled points are the reference sets of the quality indicators
+pub trait ParetoFront {
+    // nb_points fitness vectors on the Pareto front
+    fn pareto_front(&self, nb_points: usize) -> Vec<Vec<f64>>;
 }
 
 #[derive(Debug, Clone)]
@@ -28,82 +124,193 @@ where T: Problem + Clone
 {
     pub coord: Vec<f64>,
     pub fitness: Vec<f64>,
+    // fitness with the maximised objectives negated, used for every comparison
+    pub minimised_fitness: Vec<f64>,
+    // minimised fitness normalised by Nsga3 during the environmental selection
+    pub norm_fitness: Vec<f64>,
+    // total violation of the constraints of the problem
+    pub violation: f64,
 
-    problem: Rc<RefCell<T>>
+    problem: Arc<T>
 }
 
 
 impl<T> Point<T>
 where T: Problem + Clone
 {
-    pub fn new(problem: Rc<RefCell<T>>) -> Self {
-        let coord = problem.borrow().generate_random_coord();
-        let fitness: Vec<f64>=  problem.borrow().fitness(&coord);
+    pub fn new(problem: Arc<T>, rng: &mut dyn RngCore) -> Self {
+        let coord = problem.generate_random_coord(rng);
+        let fitness: Vec<f64>=  problem.fitness(&coord);
+
+        let minimised_fitness = minimise(&fitness, &problem.get_senses());
+        let violation = problem.violation(&coord, &fitness);
 
         Self {
             coord,
             fitness,
+            minimised_fitness,
+            norm_fitness: vec![],
+            violation,
             problem,
         }
     }
 
-    pub fn new_from(coord: Vec<f64>, problem: Rc<RefCell<T>>) -> Self {
-        // to disable when running
-        if !problem.borrow().is_coord_allow(&coord) {
-            panic!("Point::new_from : Coord of point not allow may be out of bounds of the dimension may mismatch");
+    pub fn new_from(coord: Vec<f64>, problem: Arc<T>) -> Self {
+        return Self::try_new_from(coord, problem).unwrap_or_else(|e| panic!("Point::new_from : {e}"));
+    }
+
+    pub fn try_new_from(coord: Vec<f64>, problem: Arc<T>) -> Result<Self, Error> {
+        if !problem.is_coord_allow(&coord) {
+            return Err(Error::CoordNotAllowed(coord));
         }
 
-        let fitness=  problem.borrow().fitness(&coord);
+        let fitness=  problem.fitness(&coord);
+        let senses = problem.get_senses();
+        if fitness.len() != problem.get_dim_objective() {
+            return Err(Error::InvalidDimObjective { coord, expected: problem.get_dim_objective(), received: fitness.len() });
+        }
+        if senses.len() != fitness.len() {
+            return Err(Error::InvalidParameter("Point::new_from : get_senses must give one sense per objective".to_string()));
+        }
 
-        Self {
+        let minimised_fitness = minimise(&fitness, &senses);
+        let violation = problem.violation(&coord, &fitness);
+
+        return Ok(Self {
             coord,
             fitness,
+            minimised_fitness,
+            norm_fitness: vec![],
+            violation,
+            problem,
+        });
+    }
+
+    // point i of a population already evaluated with problem
+    pub(crate) fn from_population(population: &Population, i: usize, problem: Arc<T>) -> Self {
+        Self {
+            coord: population.get_coord(i).to_vec(),
+            fitness: population.get_fitness(i).to_vec(),
+            minimised_fitness: population.get_minimised_fitness(i).to_vec(),
+            norm_fitness: population.get_norm_fitness(i).to_vec(),
+            violation: population.violations[i],
             problem,
         }
+    }
+
+    pub fn set_norm_fitness(&mut self, norm_fitness: Vec<f64>) {
+        self.norm_fitness = norm_fitness;
+    }
 
+    pub fn get_problem(&self) -> Arc<T> {
+        return Arc::clone(&self.problem);
     }
 
-    pub fn get_problem(&self) -> Rc<RefCell<T>> {
-        return Rc::clone(&self.problem);
+    pub fn is_feasible(&self) -> bool {
+        return self.violation <= 0.;
     }
 
+    // constraint-domination from:
+    // Kalyanmoy Deb. An efficient constraint handling method for genetic algorithms.
+    // Computer Methods in Applied Mechanics and Engineering, 186(2):311–338, 2000.
+    // a feasible point dominates an infeasible one, between two infeasible points the smallest violation dominates
+    // and two feasible points are compared with the Pareto domination
     pub fn domination(&self, other: &Self) -> Domination {
-        // self ≺(notation) other  = self dominate other
+        if self.is_feasible() != other.is_feasible() || !self.is_feasible() {
+            return match violation_order(self.violation, other.violation) {
+                std::cmp::Ordering::Less => Domination::Dominates,
+                std::cmp::Ordering::Equal => Domination::Equivalent,
+                std::cmp::Ordering::Greater => Domination::Dominated,
+            };
+        }
 
-        // pour dominer il faut que tous les critères soient meilleurs (les values >(maximisation) )
-        if self
-            .fitness
-            .clone()
-            .into_iter()
-            .enumerate()
-            .all(|(i, v)| v >= other.fitness[i]) 
-            && self
-            .fitness
-            .clone()
-            .into_iter()
-            .enumerate()
-            .any(|(i, v)| v > other.fitness[i])
-        {
-            return Domination::Dominates;
+        // every objective is compared as a minimisation, see Point::minimised_fitness
+        return pareto_domination(&self.minimised_fitness, &other.minimised_fitness);
+    }
+
+}
+
+// order of two violations, a NaN violation (from an overridden Problem::violation) is the worst one
+pub(crate) fn violation_order(a: f64, b: f64) -> std::cmp::Ordering {
+    let key = |v: f64| if v.is_nan() { f64::INFINITY } else { v };
+    return key(a).total_cmp(&key(b));
+}
+
+// Pareto domination between two fitness vectors of minimised objectives
+// a ≺(notation) b  = a dominate b
+pub fn pareto_domination(a: &[f64], b: &[f64]) -> Domination {
+    // pour dominer il faut que tous les critères soient meilleurs ou égaux (les values <=) et au moins un strictement meilleur
+    if a.iter().zip(b).all(|(x, y)| x <= y) && a.iter().zip(b).any(|(x, y)| x < y) {
+        return Domination::Dominates;
+    }
+
+    if a.iter().zip(b).all(|(x, y)| x >= y) && a.iter().zip(b).any(|(x, y)| x > y) {
+        return Domination::Dominated;
+    }
+
+    // pas domine (equivalent) => chacun a au moins un critère meilleur que l'autre
+    return Domination::Equivalent;
+}
+
+fn minimise(fitness: &Vec<f64>, senses: &Vec<Sense>) -> Vec<f64> {
+    return fitness.iter().zip(senses).map(|(&v, sense)| sense.minimised(v)).collect();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        c1dtzl1::C1DTZL1, c2dtzl2::C2DTZL2, c3dtzl4::C3DTZL4, dtzl1::DTZL1, dtzl2::DTZL2, dtzl3::DTZL3, dtzl4::DTZL4,
+        dtzl5::DTZL5, dtzl6::DTZL6, dtzl7::DTZL7, Problem,
+    };
+
+    // one objective, the constraints are given by the coord
+    struct Constrained;
+
+    impl Problem for Constrained {
+        fn fitness(&self, coord: &Vec<f64>) -> Vec<f64> {
+            return vec![coord[0]];
         }
 
-        // pas domine (equivalent)=> au moins un critère soit meilleur (une value > )
-        if self
-            .fitness
-            .clone()
-            .into_iter()
-            .enumerate()
-            .all(|(i, v)| v <= other.fitness[i]) && self
-            .fitness
-            .clone()
-            .into_iter()
-            .enumerate()
-            .any(|(i, v)| v < other.fitness[i])
-        {
-            return Domination::Dominated;
+        fn get_bounds(&self) -> (Vec<f64>, Vec<f64>) {
+            return (vec![f64::MIN; 2], vec![f64::MAX; 2]);
+        }
+
+        fn inequality_constraints(&self, coord: &Vec<f64>, _fitness: &Vec<f64>) -> Vec<f64> {
+            return vec![coord[0]];
+        }
+
+        fn equality_constraints(&self, coord: &Vec<f64>, _fitness: &Vec<f64>) -> Vec<f64> {
+            return vec![coord[1]];
         }
 
-        return Domination::Equivalent;
+        fn get_dim_objective(&self) -> usize {
+            return 1;
+        }
     }
 
+    #[test]
+    fn nan_constraints_are_violated() {
+        let problem = Constrained;
+
+        assert_eq!(problem.violation(&vec![-1., 0.], &vec![-1.]), 0.);
+        assert!((problem.violation(&vec![2., 1.], &vec![2.]) - (2. + 1. - 1e-4)).abs() < 1e-12);
+        assert_eq!(problem.violation(&vec![f64::NAN, 0.], &vec![f64::NAN]), f64::INFINITY);
+        assert_eq!(problem.violation(&vec![-1., f64::NAN], &vec![-1.]), f64::INFINITY);
+    }
+
+    #[test]
+    fn dtzl_problems_need_an_objective() {
+        assert!(DTZL1::try_new(3, 0).is_err());
+        assert!(DTZL2::try_new(3, 0).is_err());
+        assert!(DTZL3::try_new(3, 0).is_err());
+        assert!(DTZL4::try_new(3, 0).is_err());
+        assert!(DTZL5::try_new(3, 0).is_err());
+        assert!(DTZL6::try_new(3, 0).is_err());
+        assert!(DTZL7::try_new(3, 0).is_err());
+        assert!(C1DTZL1::try_new(3, 0).is_err());
+        assert!(C2DTZL2::try_new(3, 0).is_err());
+        assert!(C3DTZL4::try_new(3, 0).is_err());
+
+        assert_eq!(DTZL1::try_new(3, 1).unwrap().fitness(&vec![0.5; 3]).len(), 1);
+    }
 }
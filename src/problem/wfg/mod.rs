@@ -0,0 +1,141 @@
+// WFG toolkit from:
+// Simon Huband, Phil Hingston, Luigi Barone, and Lyndon While. A Review of Multiobjective Test Problems and a Scalable
+// Test Problem Toolkit. IEEE Transactions on Evolutionary Computation, 10(5):477–506, 2006.
+// every problem has k position parameters followed by l distance parameters, z_i being in [0, 2i]
+
+use crate::error::Error;
+
+pub mod shape;
+pub mod transformation;
+
+pub mod wfg1;
+pub mod wfg2;
+pub mod wfg3;
+pub mod wfg4;
+pub mod wfg5;
+pub mod wfg6;
+pub mod wfg7;
+pub mod wfg8;
+pub mod wfg9;
+
+fn check_parameters(name: &str, k: usize, l: usize, dim_objective: usize) -> Result<(), Error> {
+    if dim_objective < 2 {
+        return Err(Error::InvalidParameter(format!("For {name}: you must have dim_objective >= 2")));
+    }
+    if k == 0 || !k.is_multiple_of(dim_objective - 1) {
+        return Err(Error::InvalidParameter(format!("For {name}: k must be a positive multiple of dim_objective - 1")));
+    }
+    if l == 0 {
+        return Err(Error::InvalidParameter(format!("For {name}: you must have l > 0")));
+    }
+    return Ok(());
+}
+
+fn get_bounds(k: usize, l: usize) -> (Vec<f64>, Vec<f64>) {
+    return (vec![0.; k + l], (1..=k + l).map(|i| 2. * i as f64).collect());
+}
+
+// z_i in [0, 2i] -> y_i in [0, 1]
+fn normalise(coord: &Vec<f64>) -> Vec<f64> {
+    return coord.iter().enumerate().map(|(i, v)| v / (2. * (i + 1) as f64)).collect();
+}
+
+// reduce the k position parameters into dim_objective - 1 groups of the same size and the remaining (distance)
+// parameters into one value, the reduction is given a group and the index of its first element in y
+fn reduce(y: &[f64], k: usize, dim_objective: usize, reduction: impl Fn(&[f64], usize) -> f64) -> Vec<f64> {
+    let group = k / (dim_objective - 1);
+
+    let mut t: Vec<f64> = (0..dim_objective - 1)
+        .map(|m| reduction(&y[m * group..(m + 1) * group], m * group))
+        .collect();
+    t.push(reduction(&y[k..], k));
+
+    return t;
+}
+
+// last step of every WFG problem: the dim_objective reduced parameters t give the position parameters x
+// and the distance x_M, then f_m = x_M + 2m h_m(x)
+// a degenerate problem (WFG3) only keeps its first position parameter on the Pareto front
+fn objectives(t: &[f64], degenerate: bool, h: impl Fn(&[f64], usize) -> f64) -> Vec<f64> {
+    let dim_objective = t.len();
+    let distance = t[dim_objective - 1];
+
+    let x: Vec<f64> = (0..dim_objective - 1)
+        .map(|i| {
+            let a = if degenerate && i > 0 { 0. } else { 1. };
+            return distance.max(a) * (t[i] - 0.5) + 0.5;
+        })
+        .collect();
+
+    return (1..=dim_objective)
+        .map(|m| distance + 2. * m as f64 * h(&x, m))
+        .collect();
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    use super::{transformation, wfg4::WFG4, wfg5::WFG5, wfg6::WFG6, wfg7::WFG7, wfg8::WFG8, wfg9::WFG9};
+    use crate::problem::Problem;
+
+    // exponent of the parameter dependent bias of WFG8 and WFG9 for the reduction u
+    fn b_param_exponent(u: f64) -> f64 {
+        return transformation::b_param(0.5, u, 0.98 / 49.98, 0.02, 50.).ln() / 0.5_f64.ln();
+    }
+
+    fn mean(y: &[f64]) -> f64 {
+        return y.iter().sum::<f64>() / y.len() as f64;
+    }
+
+    // random position parameters, and distance parameters that give 0.35 (the optimum of the shifts) once biased
+    fn optimal_y(k: usize, l: usize, bias: &str, rng: &mut StdRng) -> Vec<f64> {
+        let n = k + l;
+        let mut y: Vec<f64> = (0..n).map(|i| if i < k { rng.gen::<f64>() } else { 0.35 }).collect();
+
+        match bias {
+            // the bias of a distance parameter depends on the parameters before it
+            "WFG8" => {
+                for i in k..n {
+                    y[i] = 0.35_f64.powf(1. / b_param_exponent(mean(&y[..i])));
+                }
+            }
+            // the bias of a parameter depends on the parameters after it, the last one is not biased
+            "WFG9" => {
+                for i in (k..n - 1).rev() {
+                    y[i] = 0.35_f64.powf(1. / b_param_exponent(mean(&y[i + 1..])));
+                }
+            }
+            _ => {}
+        }
+
+        return y;
+    }
+
+    // every front of WFG4 to WFG9 is the concave sum (f_m / 2m)^2 = 1
+    fn check_concave_front(problem: &dyn Problem, k: usize, l: usize, bias: &str) {
+        let mut rng = StdRng::seed_from_u64(0);
+
+        for _ in 0..20 {
+            let y = optimal_y(k, l, bias, &mut rng);
+            let coord: Vec<f64> = y.iter().enumerate().map(|(i, v)| 2. * (i + 1) as f64 * v).collect();
+
+            let fitness = problem.fitness(&coord);
+            let sum: f64 = fitness.iter().enumerate().map(|(m, f)| (f / (2. * (m + 1) as f64)).powi(2)).sum();
+
+            assert!((sum - 1.).abs() < 1e-6, "{} at {:?}: {}", bias, coord, sum);
+        }
+    }
+
+    #[test]
+    fn optimal_coords_are_on_the_front() {
+        for (k, l, m) in [(2, 4, 2), (4, 4, 3), (6, 5, 4)] {
+            check_concave_front(&WFG4::new(k, l, m), k, l, "WFG4");
+            check_concave_front(&WFG5::new(k, l, m), k, l, "WFG5");
+            check_concave_front(&WFG6::new(k, l, m), k, l, "WFG6");
+            check_concave_front(&WFG7::new(k, l, m), k, l, "WFG7");
+            check_concave_front(&WFG8::new(k, l, m), k, l, "WFG8");
+            check_concave_front(&WFG9::new(k, l, m), k, l, "WFG9");
+        }
+    }
+}
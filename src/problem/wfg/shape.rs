@@ -0,0 +1,47 @@
+use std::f64::consts::PI;
+
+// shape functions of the WFG toolkit, x are the M-1 position parameters and m in 1..=M is the objective
+// the names and parameters follow table I of:
+// Simon Huband, Phil Hingston, Luigi Barone, and Lyndon While. A Review of Multiobjective Test Problems and a Scalable
+// Test Problem Toolkit. IEEE Transactions on Evolutionary Computation, 10(5):477–506, 2006.
+
+pub fn linear(x: &[f64], m: usize) -> f64 {
+    let dim_objective = x.len() + 1;
+    let mut res: f64 = x.iter().take(dim_objective - m).product();
+
+    if m > 1 {
+        res *= 1. - x[dim_objective - m];
+    }
+    return res;
+}
+
+pub fn convex(x: &[f64], m: usize) -> f64 {
+    let dim_objective = x.len() + 1;
+    let mut res: f64 = x.iter().take(dim_objective - m).map(|v| 1. - (v * PI / 2.).cos()).product();
+
+    if m > 1 {
+        res *= 1. - (x[dim_objective - m] * PI / 2.).sin();
+    }
+    return res;
+}
+
+pub fn concave(x: &[f64], m: usize) -> f64 {
+    let dim_objective = x.len() + 1;
+    let mut res: f64 = x.iter().take(dim_objective - m).map(|v| (v * PI / 2.).sin()).product();
+
+    if m > 1 {
+        res *= (x[dim_objective - m] * PI / 2.).cos();
+    }
+    return res;
+}
+
+// only for the last objective, a linear front with a convex/concave segments
+pub fn mixed(x: &[f64], alpha: f64, a: f64) -> f64 {
+    let tmp = 2. * a * PI;
+    return (1. - x[0] - (tmp * x[0] + PI / 2.).cos() / tmp).powf(alpha);
+}
+
+// only for the last objective, a front made of a disconnected regions
+pub fn disc(x: &[f64], alpha: f64, beta: f64, a: f64) -> f64 {
+    return 1. - x[0].powf(alpha) * (a * x[0].powf(beta) * PI).cos().powi(2);
+}
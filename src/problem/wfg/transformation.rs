@@ -0,0 +1,79 @@
+// transformation primitives of the WFG toolkit, every one of them maps [0, 1] to [0, 1]
+// the names and parameters follow table II of:
+// Simon Huband, Phil Hingston, Luigi Barone, and Lyndon While. A Review of Multiobjective Test Problems and a Scalable
+// Test Problem Toolkit. IEEE Transactions on Evolutionary Computation, 10(5):477–506, 2006.
+
+const EPSILON: f64 = 1e-10;
+
+// remove the floating point errors that push a value slightly out of [0, 1]
+fn correct_to_01(y: f64) -> f64 {
+    if y < 0. && y >= -EPSILON {
+        return 0.;
+    }
+    if y > 1. && y <= 1. + EPSILON {
+        return 1.;
+    }
+    return y;
+}
+
+// bias: polynomial
+pub fn b_poly(y: f64, alpha: f64) -> f64 {
+    return correct_to_01(y.powf(alpha));
+}
+
+// bias: flat region, values in [b, c] are mapped to a
+pub fn b_flat(y: f64, a: f64, b: f64, c: f64) -> f64 {
+    let tmp1 = (y - b).floor().min(0.) * a * (b - y) / b;
+    let tmp2 = (c - y).floor().min(0.) * (1. - a) * (y - c) / (1. - c);
+    return correct_to_01(a + tmp1 - tmp2);
+}
+
+// bias: parameter dependent, u is usually a reduction of other parameters
+pub fn b_param(y: f64, u: f64, a: f64, b: f64, c: f64) -> f64 {
+    let v = a - (1. - 2. * u) * ((0.5 - u).floor() + a).abs();
+    return correct_to_01(y.powf(b + (c - b) * v));
+}
+
+// shift: linear, the optimum a is moved to 0
+pub fn s_linear(y: f64, a: f64) -> f64 {
+    return correct_to_01((y - a).abs() / ((a - y).floor() + a).abs());
+}
+
+// shift: deceptive, the global optimum a is surrounded by a region of width b and the deceptive optima are at c
+pub fn s_decept(y: f64, a: f64, b: f64, c: f64) -> f64 {
+    let tmp1 = (y - a + b).floor() * (1. - c + (a - b) / b) / (a - b);
+    let tmp2 = (a + b - y).floor() * (1. - c + (1. - a - b) / b) / (1. - a - b);
+    return correct_to_01(1. + ((y - a).abs() - b) * (tmp1 + tmp2 + 1. / b));
+}
+
+// shift: multi-modal, a minima with hills of size b, the global one being at c
+pub fn s_multi(y: f64, a: f64, b: f64, c: f64) -> f64 {
+    let tmp1 = (y - c).abs() / (2. * ((c - y).floor() + c));
+    let tmp2 = (4. * a + 2.) * std::f64::consts::PI * (0.5 - tmp1);
+    return correct_to_01((1. + tmp2.cos() + 4. * b * tmp1.powi(2)) / (b + 2.));
+}
+
+// reduction: weighted sum
+pub fn r_sum(y: &[f64], w: &[f64]) -> f64 {
+    let numerator: f64 = y.iter().zip(w).map(|(y, w)| y * w).sum();
+    let denominator: f64 = w.iter().sum();
+    return correct_to_01(numerator / denominator);
+}
+
+// reduction: non-separable, a is the degree of non-separability and must divide the size of y
+pub fn r_nonsep(y: &[f64], a: usize) -> f64 {
+    let n = y.len();
+    let mut numerator = 0.;
+
+    for j in 0..n {
+        numerator += y[j];
+        for k in 0..a.saturating_sub(1) {
+            numerator += (y[j] - y[(j + k + 1) % n]).abs();
+        }
+    }
+
+    let half = a.div_ceil(2) as f64;
+    let denominator = (n as f64 / a as f64) * half * (1. + 2. * a as f64 - 2. * half);
+
+    return correct_to_01(numerator / denominator);
+}
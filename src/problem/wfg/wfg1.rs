@@ -0,0 +1,73 @@
+use super::{check_parameters, get_bounds, normalise, objectives, reduce, shape, transformation};
+use crate::error::Error;
+use crate::problem::Problem;
+
+// separable, biased and mixed convex/linear front with flat regions
+#[derive(Debug, Clone)]
+pub struct WFG1 {
+    k: usize,
+    l: usize,
+    dim_objective: usize,
+}
+
+impl WFG1 {
+    // k position parameters (a multiple of dim_objective - 1) and l distance parameters
+    pub fn new(k: usize, l: usize, dim_objective: usize) -> Self {
+        return Self::try_new(k, l, dim_objective).unwrap_or_else(|e| panic!("{e}"));
+    }
+
+    pub fn try_new(k: usize, l: usize, dim_objective: usize) -> Result<Self, Error> {
+        check_parameters("WFG1", k, l, dim_objective)?;
+
+        return Ok(Self {
+            k,
+            l,
+            dim_objective,
+        });
+    }
+}
+
+impl Problem for WFG1 {
+    fn fitness(&self, coord: &Vec<f64>) -> Vec<f64> {
+        let k = self.k;
+        let y = normalise(coord);
+
+        // shift the distance parameters
+        let y: Vec<f64> = y
+            .into_iter()
+            .enumerate()
+            .map(|(i, v)| if i < k { v } else { transformation::s_linear(v, 0.35) })
+            .collect();
+
+        // flat region on the distance parameters
+        let y: Vec<f64> = y
+            .into_iter()
+            .enumerate()
+            .map(|(i, v)| if i < k { v } else { transformation::b_flat(v, 0.8, 0.75, 0.85) })
+            .collect();
+
+        // polynomial bias
+        let y: Vec<f64> = y.into_iter().map(|v| transformation::b_poly(v, 0.02)).collect();
+
+        // weighted sum with w_i = 2i
+        let t = reduce(&y, k, self.dim_objective, |group, start| {
+            let w: Vec<f64> = (start..start + group.len()).map(|i| 2. * (i + 1) as f64).collect();
+            return transformation::r_sum(group, &w);
+        });
+
+        return objectives(&t, false, |x, m| {
+            if m < self.dim_objective {
+                return shape::convex(x, m);
+            }
+            return shape::mixed(x, 1., 5.);
+        });
+    }
+
+    fn get_bounds(&self) -> (Vec<f64>, Vec<f64>) {
+        return get_bounds(self.k, self.l);
+    }
+
+    fn get_dim_objective(&self) -> usize {
+        return self.dim_objective;
+    }
+}
@@ -0,0 +1,72 @@
+use super::{check_parameters, get_bounds, normalise, objectives, reduce, shape, transformation};
+use crate::error::Error;
+use crate::problem::Problem;
+
+// non-separable with a disconnected convex front
+#[derive(Debug, Clone)]
+pub struct WFG2 {
+    k: usize,
+    l: usize,
+    dim_objective: usize,
+}
+
+impl WFG2 {
+    // k position parameters (a multiple of dim_objective - 1) and l distance parameters (even)
+    pub fn new(k: usize, l: usize, dim_objective: usize) -> Self {
+        return Self::try_new(k, l, dim_objective).unwrap_or_else(|e| panic!("{e}"));
+    }
+
+    pub fn try_new(k: usize, l: usize, dim_objective: usize) -> Result<Self, Error> {
+        check_parameters("WFG2", k, l, dim_objective)?;
+        if !l.is_multiple_of(2) {
+            return Err(Error::InvalidParameter("For WFG2: l must be even".to_string()));
+        }
+
+        return Ok(Self {
+            k,
+            l,
+            dim_objective,
+        });
+    }
+}
+
+impl Problem for WFG2 {
+    fn fitness(&self, coord: &Vec<f64>) -> Vec<f64> {
+        let k = self.k;
+        let n = self.k + self.l;
+        let y = normalise(coord);
+
+        let t = wfg2_transformations(&y, k, n, self.dim_objective);
+
+        return objectives(&t, false, |x, m| {
+            if m < self.dim_objective {
+                return shape::convex(x, m);
+            }
+            return shape::disc(x, 1., 1., 5.);
+        });
+    }
+
+    fn get_bounds(&self) -> (Vec<f64>, Vec<f64>) {
+        return get_bounds(self.k, self.l);
+    }
+
+    fn get_dim_objective(&self) -> usize {
+        return self.dim_objective;
+    }
+}
+
+// transformations shared by WFG2 and WFG3
+pub(super) fn wfg2_transformations(y: &[f64], k: usize, n: usize, dim_objective: usize) -> Vec<f64> {
+    // shift the distance parameters
+    let y: Vec<f64> = y
+        .iter()
+        .enumerate()
+        .map(|(i, &v)| if i < k { v } else { transformation::s_linear(v, 0.35) })
+        .collect();
+
+    // non-separable reduction of the distance parameters by pairs
+    let mut y_paired: Vec<f64> = y[..k].to_vec();
+    y_paired.extend((0..(n - k) / 2).map(|i| transformation::r_nonsep(&y[k + 2 * i..k + 2 * i + 2], 2)));
+
+    return reduce(&y_paired, k, dim_objective, |group, _| transformation::r_sum(group, &vec![1.; group.len()]));
+}
@@ -0,0 +1,52 @@
+use super::{check_parameters, get_bounds, normalise, objectives, shape, wfg2::wfg2_transformations};
+use crate::error::Error;
+use crate::problem::Problem;
+
+// non-separable with a degenerate linear front
+#[derive(Debug, Clone)]
+pub struct WFG3 {
+    k: usize,
+    l: usize,
+    dim_objective: usize,
+}
+
+impl WFG3 {
+    // k position parameters (a multiple of dim_objective - 1) and l distance parameters (even)
+    pub fn new(k: usize, l: usize, dim_objective: usize) -> Self {
+        return Self::try_new(k, l, dim_objective).unwrap_or_else(|e| panic!("{e}"));
+    }
+
+    pub fn try_new(k: usize, l: usize, dim_objective: usize) -> Result<Self, Error> {
+        check_parameters("WFG3", k, l, dim_objective)?;
+        if !l.is_multiple_of(2) {
+            return Err(Error::InvalidParameter("For WFG3: l must be even".to_string()));
+        }
+
+        return Ok(Self {
+            k,
+            l,
+            dim_objective,
+        });
+    }
+}
+
+impl Problem for WFG3 {
+    fn fitness(&self, coord: &Vec<f64>) -> Vec<f64> {
+        let k = self.k;
+        let n = self.k + self.l;
+        let y = normalise(coord);
+
+        // same transformations as WFG2
+        let t = wfg2_transformations(&y, k, n, self.dim_objective);
+
+        return objectives(&t, true, shape::linear);
+    }
+
+    fn get_bounds(&self) -> (Vec<f64>, Vec<f64>) {
+        return get_bounds(self.k, self.l);
+    }
+
+    fn get_dim_objective(&self) -> usize {
+        return self.dim_objective;
+    }
+}
@@ -0,0 +1,54 @@
+use super::{check_parameters, get_bounds, normalise, objectives, reduce, shape, transformation};
+use crate::error::Error;
+use crate::problem::Problem;
+
+// non-separable with a concave front
+#[derive(Debug, Clone)]
+pub struct WFG6 {
+    k: usize,
+    l: usize,
+    dim_objective: usize,
+}
+
+impl WFG6 {
+    // k position parameters (a multiple of dim_objective - 1) and l distance parameters
+    pub fn new(k: usize, l: usize, dim_objective: usize) -> Self {
+        return Self::try_new(k, l, dim_objective).unwrap_or_else(|e| panic!("{e}"));
+    }
+
+    pub fn try_new(k: usize, l: usize, dim_objective: usize) -> Result<Self, Error> {
+        check_parameters("WFG6", k, l, dim_objective)?;
+
+        return Ok(Self {
+            k,
+            l,
+            dim_objective,
+        });
+    }
+}
+
+impl Problem for WFG6 {
+    fn fitness(&self, coord: &Vec<f64>) -> Vec<f64> {
+        let k = self.k;
+        let y = normalise(coord);
+
+        // shift the distance parameters
+        let y: Vec<f64> = y
+            .into_iter()
+            .enumerate()
+            .map(|(i, v)| if i < k { v } else { transformation::s_linear(v, 0.35) })
+            .collect();
+
+        let t = reduce(&y, k, self.dim_objective, |group, _| transformation::r_nonsep(group, group.len()));
+
+        return objectives(&t, false, shape::concave);
+    }
+
+    fn get_bounds(&self) -> (Vec<f64>, Vec<f64>) {
+        return get_bounds(self.k, self.l);
+    }
+
+    fn get_dim_objective(&self) -> usize {
+        return self.dim_objective;
+    }
+}
@@ -0,0 +1,71 @@
+use super::{check_parameters, get_bounds, normalise, objectives, reduce, shape, transformation};
+use crate::error::Error;
+use crate::problem::Problem;
+
+// non-separable, deceptive and multi-modal with a concave front
+#[derive(Debug, Clone)]
+pub struct WFG9 {
+    k: usize,
+    l: usize,
+    dim_objective: usize,
+}
+
+impl WFG9 {
+    // k position parameters (a multiple of dim_objective - 1) and l distance parameters
+    pub fn new(k: usize, l: usize, dim_objective: usize) -> Self {
+        return Self::try_new(k, l, dim_objective).unwrap_or_else(|e| panic!("{e}"));
+    }
+
+    pub fn try_new(k: usize, l: usize, dim_objective: usize) -> Result<Self, Error> {
+        check_parameters("WFG9", k, l, dim_objective)?;
+
+        return Ok(Self {
+            k,
+            l,
+            dim_objective,
+        });
+    }
+}
+
+impl Problem for WFG9 {
+    fn fitness(&self, coord: &Vec<f64>) -> Vec<f64> {
+        let k = self.k;
+        let n = self.k + self.l;
+        let y = normalise(coord);
+
+        // the bias of every parameter but the last depends on all the parameters after it
+        let y: Vec<f64> = (0..n)
+            .map(|i| {
+                if i == n - 1 {
+                    return y[i];
+                }
+                let u = transformation::r_sum(&y[i + 1..], &vec![1.; n - i - 1]);
+                return transformation::b_param(y[i], u, 0.98 / 49.98, 0.02, 50.);
+            })
+            .collect();
+
+        // deceptive position parameters and multi-modal distance parameters
+        let y: Vec<f64> = y
+            .into_iter()
+            .enumerate()
+            .map(|(i, v)| {
+                if i < k {
+                    return transformation::s_decept(v, 0.35, 0.001, 0.05);
+                }
+                return transformation::s_multi(v, 30., 95., 0.35);
+            })
+            .collect();
+
+        let t = reduce(&y, k, self.dim_objective, |group, _| transformation::r_nonsep(group, group.len()));
+
+        return objectives(&t, false, shape::concave);
+    }
+
+    fn get_bounds(&self) -> (Vec<f64>, Vec<f64>) {
+        return get_bounds(self.k, self.l);
+    }
+
+    fn get_dim_objective(&self) -> usize {
+        return self.dim_objective;
+    }
+}
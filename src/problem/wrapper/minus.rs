@@ -0,0 +1,70 @@
+use rand::RngCore;
+
+use crate::problem::{Problem, Sense};
+
+// every objective of the wrapped problem negated, the minus-DTLZ and minus-WFG problems from:
+// Hisao Ishibuchi, Yu Setoguchi, Hiroyuki Masuda, and Yusuke Nojima. Performance of Decomposition-Based Many-Objective
+// Algorithms Strongly Depends on Pareto Front Shapes. IEEE Transactions on Evolutionary Computation, 21(2):169–190, 2017.
+// the senses are kept, so a minimised objective f becomes the minimisation of -f and the Pareto front is inverted
+#[derive(Debug, Clone)]
+pub struct Minus<P>
+where P: Problem + Clone
+{
+    problem: P,
+}
+
+impl<P> Minus<P>
+where P: Problem + Clone
+{
+    pub fn new(problem: P) -> Self {
+        Self { problem }
+    }
+}
+
+fn negate(fitness: &Vec<f64>) -> Vec<f64> {
+    return fitness.iter().map(|f| -f).collect();
+}
+
+impl<P> Problem for Minus<P>
+where P: Problem + Clone
+{
+    fn fitness(&self, coord: &Vec<f64>) -> Vec<f64> {
+        return negate(&self.problem.fitness(coord));
+    }
+
+    fn fitness_batch(&self, coords: &[Vec<f64>]) -> Vec<Vec<f64>> {
+        return self.problem.fitness_batch(coords).iter().map(negate).collect();
+    }
+
+    fn generate_random_coord(&self, rng: &mut dyn RngCore) -> Vec<f64> {
+        return self.problem.generate_random_coord(rng);
+    }
+
+    fn is_coord_allow(&self, coord: &Vec<f64>) -> bool {
+        return self.problem.is_coord_allow(coord);
+    }
+
+    fn get_bounds(&self) -> (Vec<f64>, Vec<f64>) {
+        return self.problem.get_bounds();
+    }
+
+    fn get_dim_objective(&self) -> usize {
+        return self.problem.get_dim_objective();
+    }
+
+    fn get_senses(&self) -> Vec<Sense> {
+        return self.problem.get_senses();
+    }
+
+    fn inequality_constraints(&self, coord: &Vec<f64>, fitness: &Vec<f64>) -> Vec<f64> {
+        return self.problem.inequality_constraints(coord, &negate(fitness));
+    }
+
+    fn equality_constraints(&self, coord: &Vec<f64>, fitness: &Vec<f64>) -> Vec<f64> {
+        return self.problem.equality_constraints(coord, &negate(fitness));
+    }
+
+    fn get_equality_tolerance(&self) -> f64 {
+        return self.problem.get_equality_tolerance();
+    }
+}
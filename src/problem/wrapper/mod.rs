@@ -0,0 +1,17 @@
+// problems built on top of another problem, to test the robustness of an algorithm without writing a new problem
+// they can be stacked, e.g. Noisy::with_sigma(Rotated::new(DTZL2::new(12, 3), 0), 0.01, 0)
+
+use rand::Rng;
+
+pub mod minus;
+pub mod noisy;
+pub mod rotated;
+pub mod scaled;
+pub mod shifted;
+
+// standard normal sample (Box-Muller)
+fn gaussian(rng: &mut impl Rng) -> f64 {
+    let u1: f64 = 1. - rng.gen::<f64>();
+    let u2: f64 = rng.gen();
+    return (-2. * u1.ln()).sqrt() * (2. * std::f64::consts::PI * u2).cos();
+}
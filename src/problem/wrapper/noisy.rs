@@ -0,0 +1,120 @@
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+use rand::{rngs::StdRng, RngCore, SeedableRng};
+
+use super::gaussian;
+use crate::error::Error;
+use crate::problem::{Problem, Sense};
+
+// gaussian noise of standard deviation sigmas[i] added to the objective i of the wrapped problem
+// the noise is drawn from the seed and the decision vector, so the same point always gets the same noise
+// whatever the order (or the thread) in which the points are evaluated
+#[derive(Debug, Clone)]
+pub struct Noisy<P>
+where P: Problem + Clone
+{
+    problem: P,
+    sigmas: Vec<f64>,
+    seed: u64,
+}
+
+impl<P> Noisy<P>
+where P: Problem + Clone
+{
+    pub fn new(problem: P, sigmas: Vec<f64>, seed: u64) -> Self {
+        return Self::try_new(problem, sigmas, seed).unwrap_or_else(|e| panic!("{e}"));
+    }
+
+    pub fn try_new(problem: P, sigmas: Vec<f64>, seed: u64) -> Result<Self, Error> {
+        if sigmas.len() != problem.get_dim_objective() {
+            return Err(Error::InvalidParameter(
+                "For Noisy: you must give one standard deviation per objective".to_string(),
+            ));
+        }
+        if sigmas.iter().any(|&s| s < 0.) {
+            return Err(Error::InvalidParameter("For Noisy: the standard deviations must be >= 0".to_string()));
+        }
+
+        return Ok(Self { problem, sigmas, seed });
+    }
+
+    // same standard deviation on every objective
+    pub fn with_sigma(problem: P, sigma: f64, seed: u64) -> Self {
+        return Self::try_with_sigma(problem, sigma, seed).unwrap_or_else(|e| panic!("{e}"));
+    }
+
+    pub fn try_with_sigma(problem: P, sigma: f64, seed: u64) -> Result<Self, Error> {
+        let sigmas = vec![sigma; problem.get_dim_objective()];
+        return Self::try_new(problem, sigmas, seed);
+    }
+
+    fn noise(&self, coord: &Vec<f64>) -> Vec<f64> {
+        let mut hasher = DefaultHasher::new();
+        self.seed.hash(&mut hasher);
+        coord.iter().for_each(|v| v.to_bits().hash(&mut hasher));
+
+        let mut rng = StdRng::seed_from_u64(hasher.finish());
+        return self.sigmas.iter().map(|s| s * gaussian(&mut rng)).collect();
+    }
+
+    // fitness of the wrapped problem
+    fn denoise(&self, coord: &Vec<f64>, fitness: &Vec<f64>) -> Vec<f64> {
+        return fitness.iter().zip(self.noise(coord)).map(|(f, n)| f - n).collect();
+    }
+}
+
+impl<P> Problem for Noisy<P>
+where P: Problem + Clone
+{
+    fn fitness(&self, coord: &Vec<f64>) -> Vec<f64> {
+        return self
+            .problem
+            .fitness(coord)
+            .into_iter()
+            .zip(self.noise(coord))
+            .map(|(f, n)| f + n)
+            .collect();
+    }
+
+    fn fitness_batch(&self, coords: &[Vec<f64>]) -> Vec<Vec<f64>> {
+        return self
+            .problem
+            .fitness_batch(coords)
+            .into_iter()
+            .zip(coords)
+            .map(|(fitness, coord)| fitness.into_iter().zip(self.noise(coord)).map(|(f, n)| f + n).collect())
+            .collect();
+    }
+
+    fn generate_random_coord(&self, rng: &mut dyn RngCore) -> Vec<f64> {
+        return self.problem.generate_random_coord(rng);
+    }
+
+    fn is_coord_allow(&self, coord: &Vec<f64>) -> bool {
+        return self.problem.is_coord_allow(coord);
+    }
+
+    fn get_bounds(&self) -> (Vec<f64>, Vec<f64>) {
+        return self.problem.get_bounds();
+    }
+
+    fn get_dim_objective(&self) -> usize {
+        return self.problem.get_dim_objective();
+    }
+
+    fn get_senses(&self) -> Vec<Sense> {
+        return self.problem.get_senses();
+    }
+
+    fn inequality_constraints(&self, coord: &Vec<f64>, fitness: &Vec<f64>) -> Vec<f64> {
+        return self.problem.inequality_constraints(coord, &self.denoise(coord, fitness));
+    }
+
+    fn equality_constraints(&self, coord: &Vec<f64>, fitness: &Vec<f64>) -> Vec<f64> {
+        return self.problem.equality_constraints(coord, &self.denoise(coord, fitness));
+    }
+
+    fn get_equality_tolerance(&self) -> f64 {
+        return self.problem.get_equality_tolerance();
+    }
+}
@@ -0,0 +1,107 @@
+use nalgebra::{DMatrix, DVector};
+use rand::{rngs::StdRng, SeedableRng};
+
+use super::gaussian;
+use crate::error::Error;
+use crate::problem::{Problem, Sense};
+
+// the wrapped problem evaluated on the decision vector rotated around the centre of the bounds,
+// which makes separable problems non-separable
+// the rotated vector is clamped to the bounds of the wrapped problem
+#[derive(Debug, Clone)]
+pub struct Rotated<P>
+where P: Problem + Clone
+{
+    problem: P,
+    rotation: DMatrix<f64>,
+}
+
+impl<P> Rotated<P>
+where P: Problem + Clone
+{
+    // random rotation, uniformly distributed among the orthogonal matrices
+    pub fn new(problem: P, seed: u64) -> Self {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let n = problem.get_bounds().0.len();
+
+        // Q of the QR decomposition of a gaussian matrix, with the signs fixed by the diagonal of R
+        let gaussian_matrix = DMatrix::<f64>::from_fn(n, n, |_, _| gaussian(&mut rng));
+        let qr = gaussian_matrix.qr();
+        let r = qr.r();
+        let mut q = qr.q();
+        for j in 0..n {
+            if r[(j, j)] < 0. {
+                q.column_mut(j).neg_mut();
+            }
+        }
+
+        return Self::with_matrix(problem, q);
+    }
+
+    // any square matrix of the size of the decision vector, it does not need to be a rotation
+    pub fn with_matrix(problem: P, rotation: DMatrix<f64>) -> Self {
+        return Self::try_with_matrix(problem, rotation).unwrap_or_else(|e| panic!("{e}"));
+    }
+
+    pub fn try_with_matrix(problem: P, rotation: DMatrix<f64>) -> Result<Self, Error> {
+        let n = problem.get_bounds().0.len();
+        if rotation.nrows() != n || rotation.ncols() != n {
+            return Err(Error::InvalidParameter(
+                "For Rotated: the matrix must be of size dim_point x dim_point".to_string(),
+            ));
+        }
+
+        return Ok(Self { problem, rotation });
+    }
+
+    // decision vector given to the wrapped problem
+    fn rotate(&self, coord: &Vec<f64>) -> Vec<f64> {
+        let (lower_b, upper_b) = self.problem.get_bounds();
+        let centre = DVector::from_iterator(coord.len(), lower_b.iter().zip(&upper_b).map(|(l, u)| (l + u) / 2.));
+
+        let rotated = &centre + &self.rotation * (DVector::from_column_slice(coord) - &centre);
+
+        return rotated
+            .iter()
+            .enumerate()
+            .map(|(i, v)| v.clamp(lower_b[i], upper_b[i]))
+            .collect();
+    }
+}
+
+impl<P> Problem for Rotated<P>
+where P: Problem + Clone
+{
+    fn fitness(&self, coord: &Vec<f64>) -> Vec<f64> {
+        return self.problem.fitness(&self.rotate(coord));
+    }
+
+    fn fitness_batch(&self, coords: &[Vec<f64>]) -> Vec<Vec<f64>> {
+        let rotated: Vec<Vec<f64>> = coords.iter().map(|coord| self.rotate(coord)).collect();
+        return self.problem.fitness_batch(&rotated);
+    }
+
+    fn get_bounds(&self) -> (Vec<f64>, Vec<f64>) {
+        return self.problem.get_bounds();
+    }
+
+    fn get_dim_objective(&self) -> usize {
+        return self.problem.get_dim_objective();
+    }
+
+    fn get_senses(&self) -> Vec<Sense> {
+        return self.problem.get_senses();
+    }
+
+    fn inequality_constraints(&self, coord: &Vec<f64>, fitness: &Vec<f64>) -> Vec<f64> {
+        return self.problem.inequality_constraints(&self.rotate(coord), fitness);
+    }
+
+    fn equality_constraints(&self, coord: &Vec<f64>, fitness: &Vec<f64>) -> Vec<f64> {
+        return self.problem.equality_constraints(&self.rotate(coord), fitness);
+    }
+
+    fn get_equality_tolerance(&self) -> f64 {
+        return self.problem.get_equality_tolerance();
+    }
+}
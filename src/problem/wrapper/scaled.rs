@@ -0,0 +1,118 @@
+use rand::RngCore;
+
+use crate::error::Error;
+use crate::problem::{ParetoFront, Problem, Sense};
+
+// objective i of the wrapped problem multiplied by factors[i], e.g. the scaled DTLZ problems of the NSGA-III paper:
+// Kalyanmoy Deb and Himanshu Jain. An Evolutionary Many-Objective Optimization Algorithm Using Reference-Point-Based
+// Nondominated Sorting Approach, Part I. IEEE Transactions on Evolutionary Computation, 18(4):577–601, 2014.
+#[derive(Debug, Clone)]
+pub struct Scaled<P>
+where P: Problem + Clone
+{
+    problem: P,
+    factors: Vec<f64>,
+}
+
+impl<P> Scaled<P>
+where P: Problem + Clone
+{
+    pub fn new(problem: P, factors: Vec<f64>) -> Self {
+        return Self::try_new(problem, factors).unwrap_or_else(|e| panic!("{e}"));
+    }
+
+    pub fn try_new(problem: P, factors: Vec<f64>) -> Result<Self, Error> {
+        if factors.len() != problem.get_dim_objective() {
+            return Err(Error::InvalidParameter("For Scaled: you must give one factor per objective".to_string()));
+        }
+        if factors.iter().any(|&f| f <= 0.) {
+            return Err(Error::InvalidParameter("For Scaled: the factors must be > 0".to_string()));
+        }
+
+        return Ok(Self { problem, factors });
+    }
+
+    // objective i multiplied by base^i
+    pub fn with_base(problem: P, base: f64) -> Self {
+        return Self::try_with_base(problem, base).unwrap_or_else(|e| panic!("{e}"));
+    }
+
+    pub fn try_with_base(problem: P, base: f64) -> Result<Self, Error> {
+        let factors = (0..problem.get_dim_objective()).map(|i| base.powi(i as i32)).collect();
+        return Self::try_new(problem, factors);
+    }
+
+    // fitness of the wrapped problem
+    fn unscale(&self, fitness: &Vec<f64>) -> Vec<f64> {
+        return fitness.iter().zip(&self.factors).map(|(f, s)| f / s).collect();
+    }
+}
+
+impl<P> Problem for Scaled<P>
+where P: Problem + Clone
+{
+    fn fitness(&self, coord: &Vec<f64>) -> Vec<f64> {
+        return self
+            .problem
+            .fitness(coord)
+            .into_iter()
+            .zip(&self.factors)
+            .map(|(f, s)| f * s)
+            .collect();
+    }
+
+    fn fitness_batch(&self, coords: &[Vec<f64>]) -> Vec<Vec<f64>> {
+        return self
+            .problem
+            .fitness_batch(coords)
+            .into_iter()
+            .map(|fitness| fitness.into_iter().zip(&self.factors).map(|(f, s)| f * s).collect())
+            .collect();
+    }
+
+    fn generate_random_coord(&self, rng: &mut dyn RngCore) -> Vec<f64> {
+        return self.problem.generate_random_coord(rng);
+    }
+
+    fn is_coord_allow(&self, coord: &Vec<f64>) -> bool {
+        return self.problem.is_coord_allow(coord);
+    }
+
+    fn get_bounds(&self) -> (Vec<f64>, Vec<f64>) {
+        return self.problem.get_bounds();
+    }
+
+    fn get_dim_objective(&self) -> usize {
+        return self.problem.get_dim_objective();
+    }
+
+    fn get_senses(&self) -> Vec<Sense> {
+        return self.problem.get_senses();
+    }
+
+    fn inequality_constraints(&self, coord: &Vec<f64>, fitness: &Vec<f64>) -> Vec<f64> {
+        return self.problem.inequality_constraints(coord, &self.unscale(fitness));
+    }
+
+    fn equality_constraints(&self, coord: &Vec<f64>, fitness: &Vec<f64>) -> Vec<f64> {
+        return self.problem.equality_constraints(coord, &self.unscale(fitness));
+    }
+
+    fn get_equality_tolerance(&self) -> f64 {
+        return self.problem.get_equality_tolerance();
+    }
+}
+
+// the Pareto front of the wrapped problem scaled
+impl<P> ParetoFront for Scaled<P>
+where P: Problem + Clone + ParetoFront
+{
+    fn pareto_front(&self, nb_points: usize) -> Vec<Vec<f64>> {
+        return self
+            .problem
+            .pareto_front(nb_points)
+            .into_iter()
+            .map(|f| f.into_iter().zip(&self.factors).map(|(f, s)| f * s).collect())
+            .collect();
+    }
+}
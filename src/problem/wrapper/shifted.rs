@@ -0,0 +1,103 @@
+use rand::RngCore;
+
+use crate::error::Error;
+use crate::problem::{ParetoFront, Problem, Sense};
+
+// shifts[i] added to the objective i of the wrapped problem
+#[derive(Debug, Clone)]
+pub struct Shifted<P>
+where P: Problem + Clone
+{
+    problem: P,
+    shifts: Vec<f64>,
+}
+
+impl<P> Shifted<P>
+where P: Problem + Clone
+{
+    pub fn new(problem: P, shifts: Vec<f64>) -> Self {
+        return Self::try_new(problem, shifts).unwrap_or_else(|e| panic!("{e}"));
+    }
+
+    pub fn try_new(problem: P, shifts: Vec<f64>) -> Result<Self, Error> {
+        if shifts.len() != problem.get_dim_objective() {
+            return Err(Error::InvalidParameter("For Shifted: you must give one shift per objective".to_string()));
+        }
+
+        return Ok(Self { problem, shifts });
+    }
+
+    // fitness of the wrapped problem
+    fn unshift(&self, fitness: &Vec<f64>) -> Vec<f64> {
+        return fitness.iter().zip(&self.shifts).map(|(f, s)| f - s).collect();
+    }
+}
+
+impl<P> Problem for Shifted<P>
+where P: Problem + Clone
+{
+    fn fitness(&self, coord: &Vec<f64>) -> Vec<f64> {
+        return self
+            .problem
+            .fitness(coord)
+            .into_iter()
+            .zip(&self.shifts)
+            .map(|(f, s)| f + s)
+            .collect();
+    }
+
+    fn fitness_batch(&self, coords: &[Vec<f64>]) -> Vec<Vec<f64>> {
+        return self
+            .problem
+            .fitness_batch(coords)
+            .into_iter()
+            .map(|fitness| fitness.into_iter().zip(&self.shifts).map(|(f, s)| f + s).collect())
+            .collect();
+    }
+
+    fn generate_random_coord(&self, rng: &mut dyn RngCore) -> Vec<f64> {
+        return self.problem.generate_random_coord(rng);
+    }
+
+    fn is_coord_allow(&self, coord: &Vec<f64>) -> bool {
+        return self.problem.is_coord_allow(coord);
+    }
+
+    fn get_bounds(&self) -> (Vec<f64>, Vec<f64>) {
+        return self.problem.get_bounds();
+    }
+
+    fn get_dim_objective(&self) -> usize {
+        return self.problem.get_dim_objective();
+    }
+
+    fn get_senses(&self) -> Vec<Sense> {
+        return self.problem.get_senses();
+    }
+
+    fn inequality_constraints(&self, coord: &Vec<f64>, fitness: &Vec<f64>) -> Vec<f64> {
+        return self.problem.inequality_constraints(coord, &self.unshift(fitness));
+    }
+
+    fn equality_constraints(&self, coord: &Vec<f64>, fitness: &Vec<f64>) -> Vec<f64> {
+        return self.problem.equality_constraints(coord, &self.unshift(fitness));
+    }
+
+    fn get_equality_tolerance(&self) -> f64 {
+        return self.problem.get_equality_tolerance();
+    }
+}
+
+// the Pareto front of the wrapped problem shifted
+impl<P> ParetoFront for Shifted<P>
+where P: Problem + Clone + ParetoFront
+{
+    fn pareto_front(&self, nb_points: usize) -> Vec<Vec<f64>> {
+        return self
+            .problem
+            .pareto_front(nb_points)
+            .into_iter()
+            .map(|f| f.into_iter().zip(&self.shifts).map(|(f, s)| f + s).collect())
+            .collect();
+    }
+}
@@ -0,0 +1,328 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use crate::error::Error;
+
+// reference directions obtained by minimising the Riesz s-energy of a set of points on the unit simplex from:
+// Julian Blank, Kalyanmoy Deb, Yashesh Dhebar, Sunith Bandaru, Haitham Seada. Generating Well-Spaced Points on a Unit Simplex
+// for Evolutionary Many-Objective Optimization. IEEE Transactions on Evolutionary Computation, 25(1):48–60, 2021.
+#[derive(Debug, Clone)]
+pub struct RieszSEnergy {
+    nb_points: usize,
+    dim_objective: usize,
+    s: f64,
+    nb_iter: usize,
+    seed: u64,
+}
+
+impl RieszSEnergy {
+    pub fn new(nb_points: usize, dim_objective: usize) -> Self {
+        return Self::try_new(nb_points, dim_objective).unwrap_or_else(|e| panic!("{e}"));
+    }
+
+    pub fn try_new(nb_points: usize, dim_objective: usize) -> Result<Self, Error> {
+        if dim_objective == 0 {
+            return Err(Error::InvalidParameter("For RieszSEnergy: you must have dim_objective > 0".to_string()));
+        }
+
+        return Ok(Self {
+            nb_points,
+            dim_objective,
+            s: dim_objective as f64,
+            nb_iter: 500,
+            seed: 0,
+        });
+    }
+
+    // exponent of the energy, the larger the more the closest neighbours matter
+    pub fn with_s(mut self, s: f64) -> Self {
+        self.s = s;
+        return self;
+    }
+
+    // maximum number of passes over the points, the descent stops before once every point has converged
+    pub fn with_nb_iter(mut self, nb_iter: usize) -> Self {
+        self.nb_iter = nb_iter;
+        return self;
+    }
+
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        return self;
+    }
+
+    pub fn generate(&self) -> Vec<Vec<f64>> {
+        let mut rng = StdRng::seed_from_u64(self.seed);
+        let m = self.dim_objective;
+
+        // the extreme points of the simplex are kept fixed, the others are drawn uniformly on it
+        let nb_fixed = m.min(self.nb_points);
+        let mut points: Vec<Vec<f64>> = (0..nb_fixed)
+            .map(|i| {
+                let mut p = vec![0.; m];
+                p[i] = 1.;
+                return p;
+            })
+            .collect();
+
+        for _ in nb_fixed..self.nb_points {
+            let mut p: Vec<f64> = (0..m).map(|_| -(1. - rng.gen::<f64>()).ln()).collect();
+            let sum: f64 = p.iter().sum();
+            p.iter_mut().for_each(|v| *v /= sum);
+            points.push(p);
+        }
+
+        if self.nb_points <= nb_fixed {
+            return points;
+        }
+
+        // every point has its own step, increased after a move that lowers the energy and decreased otherwise,
+        // so that a point stuck against its neighbours does not slow down the others
+        let mut steps = vec![0.1; points.len()];
+
+        for _ in 0..self.nb_iter {
+            for i in nb_fixed..points.len() {
+                if steps[i] < 1e-5 {
+                    continue;
+                }
+
+                let direction = self.descent_direction(&points, i);
+                let mut candidate: Vec<f64> = points[i].iter().zip(&direction).map(|(v, d)| (v + steps[i] * d).max(0.)).collect();
+                let sum: f64 = candidate.iter().sum();
+                candidate.iter_mut().for_each(|v| *v /= sum);
+
+                // only the distances to the i-th point change
+                if self.point_log_energy(&points, &candidate, i) < self.point_log_energy(&points, &points[i], i) {
+                    points[i] = candidate;
+                    steps[i] *= 1.5;
+                } else {
+                    steps[i] *= 0.5;
+                }
+            }
+
+            // every point has converged
+            if steps[nb_fixed..].iter().all(|&step| step < 1e-5) {
+                break;
+            }
+        }
+
+        return points;
+    }
+
+    // log of the part of the Riesz s-energy sum_{j != i} 1 / ||x_i - x_j||^s that depends on the i-th point,
+    // when it is at position
+    fn point_log_energy(&self, points: &Vec<Vec<f64>>, position: &[f64], i: usize) -> f64 {
+        let exponents: Vec<f64> = (0..points.len())
+            .filter(|&j| j != i)
+            .map(|j| -self.s * distance(position, &points[j]).ln())
+            .collect();
+
+        return log_sum_exp(&exponents);
+    }
+
+    // direction of steepest descent of the energy for the i-th point with norm 1, projected on the face of the simplex
+    // the point lies on (its null coordinates that would become negative are kept to 0)
+    fn descent_direction(&self, points: &Vec<Vec<f64>>, i: usize) -> Vec<f64> {
+        let m = self.dim_objective;
+
+        // -d/dx_i (1/||x_i - x_j||^s) = s (x_i - x_j) / ||x_i - x_j||^(s+2), scaled by the largest factor to avoid overflows
+        let exponents: Vec<f64> = (0..points.len())
+            .map(|j| if j == i { f64::NEG_INFINITY } else { -(self.s + 2.) * distance(&points[i], &points[j]).ln() })
+            .collect();
+        let max = exponents.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+
+        let mut direction = vec![0.; m];
+        for j in 0..points.len() {
+            if j == i {
+                continue;
+            }
+            let factor = (exponents[j] - max).exp();
+            for k in 0..m {
+                direction[k] += factor * (points[i][k] - points[j][k]);
+            }
+        }
+
+        // the coordinates of the direction sum to 0 on the free ones, until no free coordinate leaves the simplex
+        let mut free = vec![true; m];
+        loop {
+            let nb_free = free.iter().filter(|&&f| f).count();
+            let mean = (0..m).filter(|&k| free[k]).map(|k| direction[k]).sum::<f64>() / nb_free as f64;
+            for k in 0..m {
+                direction[k] = if free[k] { direction[k] - mean } else { 0. };
+            }
+
+            let blocked: Vec<usize> = (0..m).filter(|&k| free[k] && points[i][k] <= 0. && direction[k] < 0.).collect();
+            if blocked.is_empty() {
+                break;
+            }
+            blocked.into_iter().for_each(|k| free[k] = false);
+        }
+
+        let norm = direction.iter().map(|v| v * v).sum::<f64>().sqrt();
+        if norm > 0. {
+            direction.iter_mut().for_each(|v| *v /= norm);
+        }
+
+        return direction;
+    }
+}
+
+fn log_sum_exp(exponents: &[f64]) -> f64 {
+    let max = exponents.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+    let sum: f64 = exponents.iter().map(|e| (e - max).exp()).sum();
+
+    return max + sum.ln();
+}
+
+fn distance(a: &[f64], b: &[f64]) -> f64 {
+    let d = a.iter().zip(b).map(|(x, y)| (x - y).powi(2)).sum::<f64>().sqrt();
+
+    // two identical points would give an infinite energy
+    return d.max(1e-12);
+}
+
+// structured reference points on the unit simplex from:
+// Indraneel Das and John E. Dennis. Normal-boundary intersection: a new method for generating the Pareto surface in nonlinear
+// multicriteria optimization problems. SIAM Journal on Optimization, 8(3):631–657, 1998.
+// every coordinate is a multiple of 1/p, which gives C(dim_objective + p - 1, p) points
+pub fn das_dennis(dim_objective: usize, p: usize) -> Vec<Vec<f64>> {
+    return try_das_dennis(dim_objective, p).unwrap_or_else(|e| panic!("{e}"));
+}
+
+pub fn try_das_dennis(dim_objective: usize, p: usize) -> Result<Vec<Vec<f64>>, Error> {
+    if dim_objective == 0 {
+        return Err(Error::InvalidParameter("For das_dennis: you must have dim_objective > 0".to_string()));
+    }
+
+    let mut points: Vec<Vec<f64>> = vec![];
+
+    if p == 0 {
+        // only the centre of the simplex
+        points.push(vec![1. / dim_objective as f64; dim_objective]);
+        return Ok(points);
+    }
+
+    let mut current = vec![0; dim_objective];
+    das_dennis_rec(&mut points, &mut current, 0, p, p);
+
+    return Ok(points);
+}
+
+// distribute the `left` remaining divisions among the coordinates starting at `i`
+fn das_dennis_rec(points: &mut Vec<Vec<f64>>, current: &mut Vec<usize>, i: usize, left: usize, p: usize) {
+    if i == current.len() - 1 {
+        current[i] = left;
+        points.push(current.iter().map(|&v| v as f64 / p as f64).collect());
+        return;
+    }
+
+    for k in 0..=left {
+        current[i] = k;
+        das_dennis_rec(points, current, i + 1, left - k, p);
+    }
+}
+
+// two-layer reference points from section V of the NSGA-III paper:
+// Kalyanmoy Deb and Himanshu Jain. An Evolutionary Many-Objective Optimization Algorithm Using Reference-Point-Based
+// Nondominated Sorting Approach, Part I. IEEE Transactions on Evolutionary Computation, 18(4):577–601, 2014.
+// the boundary layer is a Das–Dennis lattice with p_boundary divisions, the inside layer one with p_inside divisions
+// shrunk by half towards the centre of the simplex
+pub fn two_layer(dim_objective: usize, p_boundary: usize, p_inside: usize) -> Vec<Vec<f64>> {
+    return try_two_layer(dim_objective, p_boundary, p_inside).unwrap_or_else(|e| panic!("{e}"));
+}
+
+pub fn try_two_layer(dim_objective: usize, p_boundary: usize, p_inside: usize) -> Result<Vec<Vec<f64>>, Error> {
+    let mut points = try_das_dennis(dim_objective, p_boundary)?;
+
+    let centre = 1. / dim_objective as f64;
+    for inside in try_das_dennis(dim_objective, p_inside)? {
+        points.push(inside.into_iter().map(|v| (v + centre) / 2.).collect());
+    }
+
+    return Ok(points);
+}
+
+// exactly nb_points points on the unit simplex: the largest Das–Dennis lattice that fits,
+// completed with points drawn uniformly (with a fixed seed)
+pub(crate) fn simplex_points(nb_points: usize, dim_objective: usize) -> Vec<Vec<f64>> {
+    // size of the lattice with p divisions: C(p + dim_objective - 1, dim_objective - 1)
+    let lattice_size = |p: usize| -> usize {
+        (1..dim_objective).fold(1, |acc, i| acc * (p + i) / i)
+    };
+
+    let mut points: Vec<Vec<f64>> = vec![];
+    if dim_objective > 1 && nb_points > 0 {
+        let mut p = 0;
+        while lattice_size(p + 1) <= nb_points {
+            p += 1;
+        }
+        if p > 0 {
+            points = das_dennis(dim_objective, p);
+        }
+    }
+
+    let mut rng = StdRng::seed_from_u64(0);
+    while points.len() < nb_points {
+        let mut p: Vec<f64> = (0..dim_objective).map(|_| -(1. - rng.gen::<f64>()).ln()).collect();
+        let sum: f64 = p.iter().sum();
+        p.iter_mut().for_each(|v| *v /= sum);
+        points.push(p);
+    }
+
+    return points;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{das_dennis, distance, RieszSEnergy};
+
+    fn energy(points: &Vec<Vec<f64>>, s: f64) -> f64 {
+        let mut energy = 0.;
+        for i in 0..points.len() {
+            for j in (i + 1)..points.len() {
+                energy += distance(&points[i], &points[j]).powf(-s);
+            }
+        }
+        return energy;
+    }
+
+    fn min_distance(points: &Vec<Vec<f64>>) -> f64 {
+        let mut min = f64::INFINITY;
+        for i in 0..points.len() {
+            for j in (i + 1)..points.len() {
+                min = min.min(distance(&points[i], &points[j]));
+            }
+        }
+        return min;
+    }
+
+    #[test]
+    fn points_are_on_the_simplex() {
+        for (nb_points, dim_objective) in [(1, 3), (3, 3), (20, 2), (50, 5)] {
+            let points = RieszSEnergy::new(nb_points, dim_objective).with_nb_iter(50).generate();
+
+            assert_eq!(points.len(), nb_points);
+            for p in points {
+                assert_eq!(p.len(), dim_objective);
+                assert!(p.iter().all(|&v| v >= 0.));
+                assert!((p.iter().sum::<f64>() - 1.).abs() < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn more_iterations_spread_the_points() {
+        let generator = RieszSEnergy::new(91, 3);
+        let energies: Vec<f64> = [0, 10, 50, 500]
+            .into_iter()
+            .map(|nb_iter| energy(&generator.clone().with_nb_iter(nb_iter).generate(), 3.))
+            .collect();
+
+        assert!(energies.windows(2).all(|e| e[1] < e[0]), "{:?}", energies);
+
+        // as well spread as the Das–Dennis lattice of the same size
+        let points = generator.generate();
+        let lattice = das_dennis(3, 12);
+        assert!(energy(&points, 3.) < energy(&lattice, 3.));
+        assert!(min_distance(&points) > 0.095);
+    }
+}
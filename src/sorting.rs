@@ -0,0 +1,223 @@
+use std::cmp::Ordering;
+
+use nalgebra::DMatrix;
+
+use crate::population::column;
+use crate::problem::{pareto_domination, violation_order, Domination};
+
+// algorithm used to split a population into non-dominated fronts
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SortAlgorithm {
+    // O(MN²) comparisons and O(N²) memory from:
+    // Kalyanmoy Deb, Amrit Pratap, Sameer Agarwal, and T. Meyarivan. A fast and elitist multiobjective genetic algorithm: NSGA-II.
+    // IEEE Transactions on Evolutionary Computation, 6(2):182–197, 2002.
+    FastNonDominated,
+    // efficient non-dominated sort from:
+    // Xingyi Zhang, Ye Tian, Ran Cheng, and Yaochu Jin. An Efficient Approach to Nondominated Sorting for Evolutionary
+    // Multiobjective Optimization. IEEE Transactions on Evolutionary Computation, 19(2):201–213, 2015.
+    // the points are sorted lexicographically, so that a point can only be dominated by the ones before it,
+    // then inserted one by one in the first front where nothing dominates it, found with a sequential search (ENS-SS)
+    #[default]
+    EfficientSequential,
+    // same with a binary search on the fronts (ENS-BS), better when there are many fronts
+    EfficientBinary,
+}
+
+// split the points in fronts with the constraint-domination of Point::domination:
+// the feasible points (violation <= 0) are sorted with algorithm on their minimised fitness,
+// then come the infeasible ones, one front per value of the violation in increasing order
+// fitness holds one column per point, return the index of the points of every front
+pub fn sort_fronts(fitness: &DMatrix<f64>, violations: &Vec<f64>, algorithm: SortAlgorithm) -> Vec<Vec<usize>> {
+    if fitness.ncols() != violations.len() {
+        panic!("For sort_fronts: you must have as many violations as fitness vectors");
+    }
+
+    // a NaN violation is infeasible
+    let feasible: Vec<usize> = (0..fitness.ncols()).filter(|&i| violations[i] <= 0.).collect();
+    let mut infeasible: Vec<usize> = (0..fitness.ncols()).filter(|&i| violations[i] > 0. || violations[i].is_nan()).collect();
+
+    let mut fronts = match algorithm {
+        SortAlgorithm::FastNonDominated => fast_non_dominated_sort(fitness, &feasible),
+        SortAlgorithm::EfficientSequential => efficient_non_dominated_sort(fitness, feasible, false),
+        SortAlgorithm::EfficientBinary => efficient_non_dominated_sort(fitness, feasible, true),
+    };
+
+    infeasible.sort_by(|&a, &b| violation_order(violations[a], violations[b]));
+    let mut previous: Option<f64> = None;
+    for i in infeasible {
+        if previous.is_none_or(|previous| violation_order(previous, violations[i]) != Ordering::Equal) {
+            fronts.push(vec![]);
+            previous = Some(violations[i]);
+        }
+        fronts.last_mut().unwrap().push(i);
+    }
+
+    return fronts;
+}
+
+fn fast_non_dominated_sort(fitness: &DMatrix<f64>, indices: &Vec<usize>) -> Vec<Vec<usize>> {
+    let n = indices.len();
+    // dominated[a] positions (in indices) of the points dominated by indices[a]
+    let mut dominated: Vec<Vec<usize>> = vec![vec![]; n];
+    let mut d_count: Vec<usize> = vec![0; n];
+
+    // every pair is only compared once
+    for a in 0..n {
+        for b in (a + 1)..n {
+            match pareto_domination(column(fitness, indices[a]), column(fitness, indices[b])) {
+                Domination::Dominates => {
+                    dominated[a].push(b);
+                    d_count[b] += 1;
+                }
+                Domination::Dominated => {
+                    dominated[b].push(a);
+                    d_count[a] += 1;
+                }
+                Domination::Equivalent => (),
+            }
+        }
+    }
+
+    let mut fronts: Vec<Vec<usize>> = vec![];
+    let mut current: Vec<usize> = (0..n).filter(|&a| d_count[a] == 0).collect();
+
+    while !current.is_empty() {
+        let mut next: Vec<usize> = vec![];
+        for &a in current.iter() {
+            for &b in dominated[a].iter() {
+                d_count[b] -= 1;
+                if d_count[b] == 0 {
+                    next.push(b);
+                }
+            }
+        }
+        fronts.push(current.into_iter().map(|a| indices[a]).collect());
+        current = next;
+    }
+
+    return fronts;
+}
+
+fn efficient_non_dominated_sort(fitness: &DMatrix<f64>, mut indices: Vec<usize>, binary: bool) -> Vec<Vec<usize>> {
+    indices.sort_by(|&a, &b| lexicographic(column(fitness, a), column(fitness, b)));
+
+    let mut fronts: Vec<Vec<usize>> = vec![];
+
+    // the points of a front before i can not be dominated by i, only the fronts have to be searched
+    let is_dominated_by = |front: &Vec<usize>, i: usize| -> bool {
+        // the last points added are the closest to i in the lexicographic order, so the most likely to dominate it
+        front
+            .iter()
+            .rev()
+            .any(|&k| matches!(pareto_domination(column(fitness, k), column(fitness, i)), Domination::Dominates))
+    };
+
+    for i in indices {
+        let k = if binary {
+            let (mut low, mut high) = (0, fronts.len());
+            while low < high {
+                let mid = (low + high) / 2;
+                if is_dominated_by(&fronts[mid], i) {
+                    low = mid + 1;
+                } else {
+                    high = mid;
+                }
+            }
+            low
+        } else {
+            (0..fronts.len()).find(|&k| !is_dominated_by(&fronts[k], i)).unwrap_or(fronts.len())
+        };
+
+        if k == fronts.len() {
+            fronts.push(vec![]);
+        }
+        fronts[k].push(i);
+    }
+
+    return fronts;
+}
+
+fn lexicographic(a: &[f64], b: &[f64]) -> Ordering {
+    for (x, y) in a.iter().zip(b) {
+        // total_cmp puts -0 before 0 while the domination sees them equal, adding 0 turns -0 into 0
+        match (x + 0.).total_cmp(&(y + 0.)) {
+            Ordering::Equal => continue,
+            other => return other,
+        }
+    }
+    return Ordering::Equal;
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::DMatrix;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    use super::{sort_fronts, SortAlgorithm};
+
+    // fronts with the indices of every front sorted, the order inside a front does not matter
+    fn sorted_fronts(fitness: &DMatrix<f64>, violations: &Vec<f64>, algorithm: SortAlgorithm) -> Vec<Vec<usize>> {
+        let mut fronts = sort_fronts(fitness, violations, algorithm);
+        fronts.iter_mut().for_each(|front| front.sort());
+        return fronts;
+    }
+
+    #[test]
+    fn algorithms_give_the_same_fronts() {
+        let mut rng = StdRng::seed_from_u64(0);
+
+        for _ in 0..200 {
+            let n = rng.gen_range(0..60);
+            let m = rng.gen_range(1..5);
+
+            // few distinct values so that there are ties and duplicated points, some zeros are negative
+            // as a maximised objective equal to 0 is minimised to -0
+            let fitness = DMatrix::from_fn(m, n, |_, _| match rng.gen_range(0..5) {
+                0 if rng.gen_bool(0.5) => -0.,
+                v => v as f64,
+            });
+            let violations: Vec<f64> =
+                (0..n).map(|_| if rng.gen_bool(0.3) { rng.gen_range(1..4) as f64 * 0.5 } else { 0. }).collect();
+
+            let fast = sorted_fronts(&fitness, &violations, SortAlgorithm::FastNonDominated);
+            assert_eq!(fast, sorted_fronts(&fitness, &violations, SortAlgorithm::EfficientSequential));
+            assert_eq!(fast, sorted_fronts(&fitness, &violations, SortAlgorithm::EfficientBinary));
+
+            // every point is in exactly one front
+            let mut all: Vec<usize> = fast.into_iter().flatten().collect();
+            all.sort();
+            assert_eq!(all, (0..n).collect::<Vec<usize>>());
+        }
+    }
+
+    #[test]
+    fn signed_zeros_are_equal() {
+        // (0, 0) dominates (-0, 1)
+        let fitness = DMatrix::from_vec(2, 2, vec![-0., 1., 0., 0.]);
+
+        for algorithm in [SortAlgorithm::FastNonDominated, SortAlgorithm::EfficientSequential, SortAlgorithm::EfficientBinary] {
+            assert_eq!(sorted_fronts(&fitness, &vec![0.; 2], algorithm), vec![vec![1], vec![0]]);
+        }
+    }
+
+    #[test]
+    fn nan_violations_are_the_worst() {
+        let fitness = DMatrix::from_vec(2, 4, vec![0., 0., 1., 1., 2., 2., 3., 3.]);
+        let violations = vec![f64::NAN, 0., -f64::NAN, 1.];
+
+        for algorithm in [SortAlgorithm::FastNonDominated, SortAlgorithm::EfficientSequential, SortAlgorithm::EfficientBinary] {
+            assert_eq!(sorted_fronts(&fitness, &violations, algorithm), vec![vec![1], vec![3], vec![0, 2]]);
+        }
+    }
+
+    #[test]
+    fn infeasible_points_come_last_by_violation() {
+        // the infeasible point (0, 0) dominates everything but is in the last front
+        let fitness = DMatrix::from_vec(2, 4, vec![1., 2., 2., 1., 0., 0., 3., 3.]);
+        let violations = vec![0., 0., 1., 0.5];
+
+        for algorithm in [SortAlgorithm::FastNonDominated, SortAlgorithm::EfficientSequential, SortAlgorithm::EfficientBinary] {
+            assert_eq!(sorted_fronts(&fitness, &violations, algorithm), vec![vec![0, 1], vec![3], vec![2]]);
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use std::fmt;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+// indicator of a Termination::Stagnation, a closure can capture a reference set or a reference point
+pub type Indicator = Arc<dyn Fn(&Vec<Vec<f64>>) -> f64 + Send + Sync>;
+
+// criteria used by Nsga3::run to stop, the run ends as soon as one of them is reached
+#[derive(Clone)]
+pub enum Termination {
+    MaxGenerations(usize),
+    MaxEvaluations(usize),
+    TimeBudget(Duration),
+    // the indicator, computed on the minimised fitness of the non-dominated points, did not move
+    // by more than tol during nb_generations generations
+    Stagnation {
+        indicator: Indicator,
+        nb_generations: usize,
+        tol: f64,
+    },
+}
+
+impl fmt::Debug for Termination {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Termination::MaxGenerations(max) => f.debug_tuple("MaxGenerations").field(max).finish(),
+            Termination::MaxEvaluations(max) => f.debug_tuple("MaxEvaluations").field(max).finish(),
+            Termination::TimeBudget(budget) => f.debug_tuple("TimeBudget").field(budget).finish(),
+            Termination::Stagnation { nb_generations, tol, .. } => f
+                .debug_struct("Stagnation")
+                .field("nb_generations", nb_generations)
+                .field("tol", tol)
+                .finish(),
+        }
+    }
+}
+
+// progress of a run, as seen by the termination criteria
+#[derive(Debug, Clone)]
+pub struct Progress {
+    pub generation: usize,
+    pub nb_evaluations: usize,
+    pub start: Instant,
+}
+
+impl Termination {
+    // history holds the values of the indicator since it last moved, it is only used by Stagnation
+    pub(crate) fn is_reached(&self, progress: &Progress, front: Option<&Vec<Vec<f64>>>, history: &mut Vec<f64>) -> bool {
+        match self {
+            Termination::MaxGenerations(max) => progress.generation >= *max,
+            Termination::MaxEvaluations(max) => progress.nb_evaluations >= *max,
+            Termination::TimeBudget(budget) => progress.start.elapsed() >= *budget,
+            Termination::Stagnation { indicator, nb_generations, tol } => {
+                let front = match front {
+                    Some(front) => front,
+                    None => return false,
+                };
+                let value = indicator(front);
+
+                if history.first().is_some_and(|&first| (value - first).abs() > *tol) {
+                    history.clear();
+                }
+                history.push(value);
+
+                return history.len() > *nb_generations;
+            }
+        }
+    }
+
+    pub(crate) fn needs_front(&self) -> bool {
+        return matches!(self, Termination::Stagnation { .. });
+    }
+}